@@ -0,0 +1,342 @@
+use crate::derive::{IntoIter, Iter};
+use crate::integer::Integer;
+use crate::traits::*;
+use core::ops::{Range, RangeInclusive};
+
+/// An arithmetic progression of integers.
+///
+/// The sequence consists of the `len` integers `start`, `start + step`,
+/// `start + 2 * step`, and so on. Unlike [`core::iter::StepBy`] applied to a
+/// range, the step may be negative for signed integers.
+///
+/// # Examples
+///
+/// ```
+/// use sqnc::{Arange, Sequence};
+///
+/// let x = Arange::new(10, -3, 4).unwrap();
+/// assert!(x.iter().eq([10, 7, 4, 1]));
+/// assert_eq!(x.get(2), Some(4));
+/// assert_eq!(x.min(), Some(1));
+/// assert_eq!(x.max(), Some(10));
+///
+/// // The step must be nonzero and the last element must be representable.
+/// assert!(Arange::<u8>::new(1, 0, 4).is_none());
+/// assert!(Arange::<u8>::new(200, 20, 4).is_none());
+/// ```
+///
+/// Only [`Range<usize>`] implements [`Sequence`] directly. Ranges of other
+/// integer types can be converted into an [`Arange`] with step one:
+///
+/// ```
+/// use sqnc::{Arange, Sequence};
+///
+/// let x = Arange::from_range(-2..2i32).unwrap();
+/// assert!(x.iter().eq([-2, -1, 0, 1]));
+/// let y = Arange::from_range_inclusive(250..=u8::MAX).unwrap();
+/// assert_eq!(y.last(), Some(u8::MAX));
+///
+/// // The length must not exceed `usize::MAX`.
+/// assert!(Arange::from_range_inclusive(0..=usize::MAX).is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Arange<T> {
+    start: T,
+    step: T,
+    len: usize,
+}
+
+impl<T> Arange<T>
+where
+    T: Integer,
+{
+    /// Returns an arithmetic progression of length `len`.
+    ///
+    /// Returns `None` if `step` is zero or if the last element overflows.
+    #[inline]
+    pub fn new(start: T, step: T, len: usize) -> Option<Self> {
+        if step.is_zero() {
+            return None;
+        }
+        if let Some(n) = len.checked_sub(1) {
+            start.checked_step(step, n)?;
+        }
+        Some(Self { start, step, len })
+    }
+
+    /// Returns the integers in `range` or `None` if the length of the range
+    /// exceeds [`usize::MAX`].
+    #[inline]
+    pub fn from_range(range: Range<T>) -> Option<Self> {
+        let len = range.start.checked_distance(range.end)?;
+        Some(Self {
+            start: range.start,
+            step: T::ONE,
+            len,
+        })
+    }
+
+    /// Returns the integers in `range` or `None` if the length of the range
+    /// exceeds [`usize::MAX`].
+    #[inline]
+    pub fn from_range_inclusive(range: RangeInclusive<T>) -> Option<Self> {
+        let (start, end) = range.into_inner();
+        let len = if start <= end {
+            start.checked_distance(end)?.checked_add(1)?
+        } else {
+            0
+        };
+        Some(Self {
+            start,
+            step: T::ONE,
+            len,
+        })
+    }
+
+    /// Returns the first element of the progression, regardless of its length.
+    #[inline]
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    /// Returns the step of the progression.
+    #[inline]
+    pub fn step(&self) -> T {
+        self.step
+    }
+}
+
+impl<'this, T> SequenceTypes<'this> for Arange<T>
+where
+    T: Integer,
+{
+    type Item = T;
+    type Iter = Iter<'this, Self>;
+}
+
+impl<T> Sequence for Arange<T>
+where
+    T: Integer,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<T> {
+        // The constructor guarantees that all elements are representable,
+        // hence wrapping arithmetic gives the exact result.
+        (index < self.len).then(|| self.start.wrapping_step(self.step, index))
+    }
+
+    #[inline]
+    fn iter(&self) -> Iter<'_, Self> {
+        self.into()
+    }
+
+    #[inline]
+    fn min<'a>(&'a self) -> Option<T>
+    where
+        <Self as SequenceTypes<'a>>::Item: Ord,
+    {
+        if self.step.is_positive() {
+            self.first()
+        } else {
+            self.last()
+        }
+    }
+
+    #[inline]
+    fn max<'a>(&'a self) -> Option<T>
+    where
+        <Self as SequenceTypes<'a>>::Item: Ord,
+    {
+        if self.step.is_positive() {
+            self.last()
+        } else {
+            self.first()
+        }
+    }
+}
+
+impl<T> IntoIterator for Arange<T>
+where
+    T: Integer,
+{
+    type Item = T;
+    type IntoIter = IntoIter<Self>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.into()
+    }
+}
+
+// SAFETY: The step is nonzero and the elements don't overflow, hence `Arange`
+// is strict monotonic.
+unsafe impl<T> UniqueSequence for Arange<T> where T: Integer {}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "Arange")]
+struct ArangeFields<T> {
+    start: T,
+    step: T,
+    len: usize,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Arange<T>
+where
+    T: Integer + serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let ArangeFields { start, step, len } = ArangeFields::deserialize(deserializer)?;
+        Self::new(start, step, len).ok_or_else(|| {
+            serde::de::Error::custom("the step is zero or the last element overflows")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Arange;
+    use crate::traits::*;
+
+    #[test]
+    fn new() {
+        assert!(Arange::<u8>::new(0, 1, 256).is_some());
+        assert!(Arange::<u8>::new(0, 1, 257).is_none());
+        assert!(Arange::<u8>::new(5, 50, 6).is_some());
+        assert!(Arange::<u8>::new(5, 50, 7).is_none());
+        assert!(Arange::<u8>::new(5, 0, 1).is_none());
+        assert!(Arange::<u8>::new(u8::MAX, 1, 1).is_some());
+        assert!(Arange::<u8>::new(u8::MAX, 1, 0).is_some());
+        assert!(Arange::<i8>::new(-128, 1, 256).is_some());
+        assert!(Arange::<i8>::new(127, -1, 256).is_some());
+        assert!(Arange::<i8>::new(127, -1, 257).is_none());
+        assert!(Arange::<i8>::new(-128, 127, 3).is_some());
+        assert!(Arange::<i8>::new(-128, 127, 4).is_none());
+        assert!(Arange::<i8>::new(0, 0, 0).is_none());
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn from_range() {
+        assert!(Arange::from_range(-2..2i8).unwrap().iter().eq(-2..2));
+        assert!(Arange::from_range(i8::MIN..i8::MAX)
+            .unwrap()
+            .iter()
+            .eq(i8::MIN..i8::MAX));
+        assert!(Arange::from_range(5..2u8).unwrap().is_empty());
+        assert!(Arange::from_range(0..u128::MAX).is_none());
+        assert_eq!(Arange::from_range(0..usize::MAX).unwrap().len(), usize::MAX);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn from_range_inclusive() {
+        let x = Arange::from_range_inclusive(i8::MIN..=i8::MAX).unwrap();
+        assert_eq!(x.len(), 256);
+        assert_eq!(x.last(), Some(i8::MAX));
+        assert!(Arange::from_range_inclusive(5..=2u8).unwrap().is_empty());
+        assert!(Arange::from_range_inclusive(0..=usize::MAX).is_none());
+        assert!(Arange::from_range_inclusive(0..=u128::MAX).is_none());
+    }
+
+    #[test]
+    fn start_step() {
+        let x = Arange::<i16>::new(3, -2, 5).unwrap();
+        assert_eq!(x.start(), 3);
+        assert_eq!(x.step(), -2);
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(Arange::<u32>::new(3, 2, 5).unwrap().len(), 5);
+        assert_eq!(Arange::<u32>::new(3, 2, 0).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn get() {
+        let x = Arange::<u64>::new(3, 2, 4).unwrap();
+        assert_eq!(x.get(0), Some(3));
+        assert_eq!(x.get(1), Some(5));
+        assert_eq!(x.get(3), Some(9));
+        assert_eq!(x.get(4), None);
+        let y = Arange::<i8>::new(127, -1, 256).unwrap();
+        assert_eq!(y.get(0), Some(127));
+        assert_eq!(y.get(200), Some(-73));
+        assert_eq!(y.get(255), Some(-128));
+        assert_eq!(y.get(256), None);
+    }
+
+    #[test]
+    fn rget() {
+        let x = Arange::<isize>::new(3, -2, 4).unwrap();
+        assert_eq!(x.rget(0), Some(-3));
+        assert_eq!(x.rget(3), Some(3));
+        assert_eq!(x.rget(4), None);
+    }
+
+    #[test]
+    fn iter() {
+        let x = Arange::<i128>::new(3, -2, 4).unwrap();
+        assert!(x.iter().eq([3, 1, -1, -3]));
+        assert!(x.iter().rev().eq([-3, -1, 1, 3]));
+    }
+
+    #[test]
+    fn min() {
+        assert_eq!(Arange::<i32>::new(3, 2, 4).unwrap().min(), Some(3));
+        assert_eq!(Arange::<i32>::new(3, -2, 4).unwrap().min(), Some(-3));
+        assert_eq!(Arange::<i32>::new(3, -2, 0).unwrap().min(), None);
+    }
+
+    #[test]
+    fn max() {
+        assert_eq!(Arange::<usize>::new(3, 2, 4).unwrap().max(), Some(9));
+        assert_eq!(Arange::<i32>::new(3, -2, 4).unwrap().max(), Some(3));
+        assert_eq!(Arange::<i32>::new(3, -2, 0).unwrap().max(), None);
+    }
+
+    #[test]
+    fn select() {
+        let x = *b"abcdefg";
+        let y = x.select(Arange::new(5, 2, 1).unwrap()).unwrap();
+        assert!(y.iter().eq(b"f"));
+        let z = x.select(Arange::new(6, 3, 2).unwrap());
+        assert!(z.is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+        let tokens = |step| {
+            [
+                Token::Struct {
+                    name: "Arange",
+                    len: 3,
+                },
+                Token::Str("start"),
+                Token::I8(1),
+                Token::Str("step"),
+                Token::I8(step),
+                Token::Str("len"),
+                Token::U64(3),
+                Token::StructEnd,
+            ]
+        };
+        assert_tokens(&Arange::<i8>::new(1, -2, 3).unwrap(), &tokens(-2));
+        assert_de_tokens_error::<Arange<i8>>(
+            &tokens(0),
+            "the step is zero or the last element overflows",
+        );
+    }
+}
@@ -0,0 +1,290 @@
+use crate::derive::Iter;
+use crate::traits::*;
+use crate::{Slice, SliceMut, Wrapper};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ops::Range;
+
+/// A sequence of non-overlapping chunks of a sequence.
+///
+/// This struct is created by [`Sequence::sqnc_chunks()`] and
+/// [`Sequence::sqnc_chunks_exact()`]. See their documentation for more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Chunks<Seq> {
+    seq: Seq,
+    size: usize,
+    len: usize,
+}
+
+impl<Seq> Chunks<Seq>
+where
+    Seq: Sequence,
+{
+    #[inline]
+    pub(crate) fn new(seq: Seq, size: usize) -> Option<Self> {
+        let n = seq.len();
+        let len = n.checked_div(size)? + usize::from(n % size != 0);
+        Some(Self { seq, size, len })
+    }
+
+    #[inline]
+    pub(crate) fn new_exact(seq: Seq, size: usize) -> Option<Self> {
+        let len = seq.len().checked_div(size)?;
+        Some(Self { seq, size, len })
+    }
+}
+
+/// Returns the range of indices of chunk `index` or `None` if out of bounds.
+#[inline]
+fn chunk_range(index: usize, len: usize, size: usize, seq_len: usize) -> Option<Range<usize>> {
+    if index < len {
+        let start = index * size;
+        Some(start..start + size.min(seq_len - start))
+    } else {
+        None
+    }
+}
+
+impl<'this, Seq> SequenceTypes<'this> for Chunks<Seq>
+where
+    Seq: Sequence,
+{
+    type Item = Slice<Wrapper<&'this Seq, ((),)>>;
+    type Iter = Iter<'this, Self>;
+}
+
+impl<Seq> Sequence for Chunks<Seq>
+where
+    Seq: Sequence,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<<Self as SequenceTypes<'_>>::Item> {
+        let range = chunk_range(index, self.len, self.size, self.seq.len())?;
+        Slice::new(self.seq.as_sqnc(), range)
+    }
+
+    #[inline]
+    fn iter(&self) -> Iter<'_, Self> {
+        self.into()
+    }
+}
+
+// SAFETY: The chunks for unique indices cover disjoint ranges of indices of
+// the parent sequence. If the parent is unique, the elements of different
+// chunks are unique.
+unsafe impl<Seq> UniqueSequence for Chunks<Seq> where Seq: UniqueSequence {}
+
+/// An iterator over non-overlapping mutable chunks of a sequence.
+///
+/// This struct is created by [`MutSequence::sqnc_chunks_mut()`]. See its
+/// documentation for more.
+pub struct ChunksMut<'seq, Seq: ?Sized> {
+    seq: *mut Seq,
+    seq_len: usize,
+    size: usize,
+    len: usize,
+    index: Range<usize>,
+    phantom: PhantomData<&'seq mut Seq>,
+}
+
+impl<'seq, Seq> ChunksMut<'seq, Seq>
+where
+    Seq: UniqueMutSequence + ?Sized,
+{
+    #[inline]
+    pub(crate) fn new(seq: &'seq mut Seq, size: usize) -> Option<Self> {
+        let seq_len = seq.len();
+        let len = seq_len.checked_div(size)? + usize::from(seq_len % size != 0);
+        Some(Self {
+            seq,
+            seq_len,
+            size,
+            len,
+            index: 0..len,
+            phantom: PhantomData,
+        })
+    }
+
+    #[inline]
+    fn chunk(&self, index: usize) -> Option<SliceMut<'seq, Seq>> {
+        let range = chunk_range(index, self.len, self.size, self.seq_len)?;
+        // SAFETY: The parent sequence is borrowed mutably for `'seq` and
+        // `range` is in bounds. Every chunk is returned at most once and the
+        // chunks cover disjoint ranges of indices.
+        unsafe { Some(SliceMut::new(self.seq, range)) }
+    }
+}
+
+impl<'seq, Seq> Iterator for ChunksMut<'seq, Seq>
+where
+    Seq: UniqueMutSequence + ?Sized,
+{
+    type Item = SliceMut<'seq, Seq>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.index.next()?;
+        self.chunk(index)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.index.size_hint()
+    }
+}
+
+impl<'seq, Seq> DoubleEndedIterator for ChunksMut<'seq, Seq>
+where
+    Seq: UniqueMutSequence + ?Sized,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.index.next_back()?;
+        self.chunk(index)
+    }
+}
+
+impl<'seq, Seq> ExactSizeIterator for ChunksMut<'seq, Seq> where Seq: UniqueMutSequence + ?Sized {}
+
+impl<'seq, Seq> FusedIterator for ChunksMut<'seq, Seq> where Seq: UniqueMutSequence + ?Sized {}
+
+#[cfg(test)]
+mod tests {
+    use super::{Chunks, ChunksMut};
+    use crate::traits::*;
+
+    #[test]
+    fn new() {
+        assert!(Chunks::new(0..3, 2).is_some());
+        assert!(Chunks::new(0..3, 0).is_none());
+        assert!(Chunks::new_exact(0..3, 2).is_some());
+        assert!(Chunks::new_exact(0..3, 0).is_none());
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(Chunks::new(0..6, 2).unwrap().len(), 3);
+        assert_eq!(Chunks::new(0..7, 2).unwrap().len(), 4);
+        assert_eq!(Chunks::new(0..1, 2).unwrap().len(), 1);
+        assert_eq!(Chunks::new(0..0, 2).unwrap().len(), 0);
+        assert_eq!(
+            Chunks::new(0..usize::MAX, 2).unwrap().len(),
+            usize::MAX / 2 + 1
+        );
+        assert_eq!(Chunks::new_exact(0..6, 2).unwrap().len(), 3);
+        assert_eq!(Chunks::new_exact(0..7, 2).unwrap().len(), 3);
+        assert_eq!(Chunks::new_exact(0..1, 2).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(!Chunks::new(0..1, 2).unwrap().is_empty());
+        assert!(Chunks::new(0..0, 2).unwrap().is_empty());
+        assert!(Chunks::new_exact(0..1, 2).unwrap().is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let x = Chunks::new(2..7, 2).unwrap();
+        assert!(x.get(0).unwrap().iter().eq(2..4));
+        assert!(x.get(1).unwrap().iter().eq(4..6));
+        assert!(x.get(2).unwrap().iter().eq(6..7));
+        assert!(x.get(3).is_none());
+        let y = Chunks::new_exact(2..7, 2).unwrap();
+        assert!(y.get(1).unwrap().iter().eq(4..6));
+        assert!(y.get(2).is_none());
+    }
+
+    #[test]
+    fn rget() {
+        let x = Chunks::new(2..7, 2).unwrap();
+        assert!(x.rget(0).unwrap().iter().eq(6..7));
+        assert!(x.rget(1).unwrap().iter().eq(4..6));
+        assert!(x.rget(2).unwrap().iter().eq(2..4));
+        assert!(x.rget(3).is_none());
+    }
+
+    #[test]
+    fn iter() {
+        let x = Chunks::new([2, 3, 4, 5, 6], 2).unwrap();
+        let mut iter = x.iter();
+        assert!(iter.next().unwrap().iter().eq(&[2, 3]));
+        assert!(iter.next().unwrap().iter().eq(&[4, 5]));
+        assert!(iter.next().unwrap().iter().eq(&[6]));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn rev_iter() {
+        let x = Chunks::new_exact([2, 3, 4, 5, 6], 2).unwrap();
+        let mut iter = x.iter().rev();
+        assert!(iter.next().unwrap().iter().eq(&[4, 5]));
+        assert!(iter.next().unwrap().iter().eq(&[2, 3]));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn chunks_mut() {
+        let mut x = [2, 3, 4, 5, 6];
+        let mut iter = ChunksMut::new(&mut x, 2).unwrap();
+        let mut a = iter.next().unwrap();
+        let mut b = iter.next().unwrap();
+        let mut c = iter.next().unwrap();
+        assert!(iter.next().is_none());
+        assert_eq!(a.len(), 2);
+        assert_eq!(c.len(), 1);
+        b.iter_mut().for_each(|v| *v += 20);
+        a.iter_mut().for_each(|v| *v += 10);
+        *c.first_mut().unwrap() += 30;
+        assert!(b.iter().eq(&[24, 25]));
+        assert_eq!(x, [12, 13, 24, 25, 36]);
+        assert!(ChunksMut::new(&mut x, 0).is_none());
+    }
+
+    #[test]
+    fn rev_chunks_mut() {
+        let mut x = [2, 3, 4, 5, 6];
+        let mut iter = ChunksMut::new(&mut x, 2).unwrap().rev();
+        *iter.next().unwrap().first_mut().unwrap() = 0;
+        *iter.next().unwrap().first_mut().unwrap() = 1;
+        *iter.next().unwrap().first_mut().unwrap() = 2;
+        assert!(iter.next().is_none());
+        assert_eq!(x, [2, 3, 1, 5, 0]);
+    }
+
+    #[test]
+    fn chunks_mut_size_hint() {
+        let mut x = [2, 3, 4, 5, 6];
+        let mut iter = ChunksMut::new(&mut x, 2).unwrap();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        iter.next();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(ChunksMut::new(&mut x, 5).unwrap().len(), 1);
+        assert_eq!(ChunksMut::new(&mut x, 6).unwrap().len(), 1);
+        assert_eq!(ChunksMut::new(&mut [0; 0], 2).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn rev_parent_chunks_mut() {
+        let mut x = [0, 1, 2, 3, 4];
+        let mut y = x.as_mut_sqnc().rev();
+        for (i, mut chunk) in ChunksMut::new(&mut y, 2).unwrap().enumerate() {
+            chunk.iter_mut().for_each(|v| *v = i);
+        }
+        assert_eq!(x, [2, 1, 1, 0, 0]);
+    }
+
+    #[test]
+    fn slice_chunks() {
+        // `slice::chunks()` is not shadowed by `Sequence::sqnc_chunks()`.
+        let x = [1, 2, 3, 4, 5];
+        let mut iter = x.chunks(2);
+        assert_eq!(iter.next().unwrap()[1], 2);
+        assert_eq!(iter.count(), 2);
+    }
+}
@@ -0,0 +1,152 @@
+extern crate alloc;
+use crate::derive::{IntoIter, Iter};
+use crate::traits::*;
+use alloc::vec::Vec;
+
+/// The indices of the `true` elements of a mask.
+///
+/// This struct is created by [`Sequence::compress()`] as indices of a
+/// [`Select`][`crate::Select`]. The indices are strictly increasing, hence
+/// [`MaskIndices`] implements [`UniqueSequence`]. Requires feature `alloc`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaskIndices {
+    indices: Vec<usize>,
+}
+
+impl MaskIndices {
+    pub(crate) fn new<Mask>(mask: &Mask) -> Self
+    where
+        Mask: Sequence + for<'a> SequenceTypes<'a, Item = bool>,
+    {
+        let indices = mask
+            .iter()
+            .enumerate()
+            .filter_map(|(i, m)| m.then_some(i))
+            .collect();
+        Self { indices }
+    }
+
+    /// Returns the indices as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[usize] {
+        &self.indices
+    }
+}
+
+impl<'this> SequenceTypes<'this> for MaskIndices {
+    type Item = usize;
+    type Iter = Iter<'this, Self>;
+}
+
+impl Sequence for MaskIndices {
+    #[inline]
+    fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<usize> {
+        self.indices.get(index).copied()
+    }
+
+    #[inline]
+    fn iter(&self) -> Iter<'_, Self> {
+        self.into()
+    }
+
+    #[inline]
+    fn min<'a>(&'a self) -> Option<usize>
+    where
+        <Self as SequenceTypes<'a>>::Item: Ord,
+    {
+        self.first()
+    }
+
+    #[inline]
+    fn max<'a>(&'a self) -> Option<usize>
+    where
+        <Self as SequenceTypes<'a>>::Item: Ord,
+    {
+        self.last()
+    }
+}
+
+// SAFETY: The indices are strictly increasing by construction and are owned
+// by `MaskIndices`, which doesn't provide mutable access.
+unsafe impl UniqueSequence for MaskIndices {}
+
+impl IntoIterator for MaskIndices {
+    type Item = usize;
+    type IntoIter = IntoIter<Self>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MaskIndices;
+    use crate::traits::*;
+
+    #[test]
+    fn new() {
+        let x = MaskIndices::new(&[true, false, false, true, true].copied());
+        assert_eq!(x.as_slice(), &[0, 3, 4]);
+        let y = MaskIndices::new(&[false, false].copied());
+        assert_eq!(y.as_slice(), &[]);
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(MaskIndices::new(&[true, false, true].copied()).len(), 2);
+        assert_eq!(MaskIndices::new(&[false].copied()).len(), 0);
+    }
+
+    #[test]
+    fn get() {
+        let x = MaskIndices::new(&[false, true, false, true].copied());
+        assert_eq!(x.get(0), Some(1));
+        assert_eq!(x.get(1), Some(3));
+        assert_eq!(x.get(2), None);
+    }
+
+    #[test]
+    fn iter() {
+        let x = MaskIndices::new(&[false, true, false, true].copied());
+        assert!(x.iter().eq([1, 3]));
+        assert!(x.iter().rev().eq([3, 1]));
+        assert!(x.into_iter().eq([1, 3]));
+    }
+
+    #[test]
+    fn min_max() {
+        let x = MaskIndices::new(&[false, true, true, false].copied());
+        assert_eq!(x.min(), Some(1));
+        assert_eq!(x.max(), Some(2));
+        let y = MaskIndices::new(&[false].copied());
+        assert_eq!(y.min(), None);
+        assert_eq!(y.max(), None);
+    }
+
+    #[test]
+    fn compress() {
+        let x = *b"abcde";
+        let y = x
+            .compress([true, false, true, true, false].copied())
+            .unwrap();
+        assert!(y.iter().eq(b"acd"));
+        assert!(x.compress([true, false].copied()).is_none());
+    }
+
+    #[test]
+    fn compress_mut() {
+        let mut x = [0, 1, 2, 3, 4];
+        let mask = Sequence::map(0..5, |i| i % 2 == 0);
+        let mut y = x.as_mut_sqnc().compress(mask).unwrap();
+        y.iter_mut().for_each(|v| *v *= 10);
+        *y.last_mut().unwrap() = 5;
+        assert_eq!(x, [0, 1, 20, 3, 5]);
+    }
+}
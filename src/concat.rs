@@ -146,6 +146,18 @@ where
     }
 }
 
+// SAFETY: `Seq0` and `Seq1` are different fields of `Concat`, hence the
+// mutable elements of `Seq0` don't alias those of `Seq1`. If both parts have
+// unique mutable elements, so does the concatenation.
+unsafe impl<Seq0, Seq1> UniqueMutSequence for Concat<Seq0, Seq1>
+where
+    Seq0: UniqueMutSequence,
+    Seq1: UniqueMutSequence
+        + for<'a> SequenceTypes<'a, Item = <Seq0 as SequenceTypes<'a>>::Item>
+        + for<'a> MutSequenceTypes<'a, MutItem = <Seq0 as MutSequenceTypes<'a>>::MutItem>,
+{
+}
+
 impl<Seq0, Seq1, Item> IntoIterator for Concat<Seq0, Seq1>
 where
     Seq0: Sequence + for<'a> SequenceTypes<'a, Item = Item> + IntoIterator<Item = Item>,
@@ -408,8 +420,42 @@ mod tests {
         assert_eq!(y, [7, 8]);
     }
 
+    #[test]
+    fn chunks_mut() {
+        let mut x = [2, 3, 4];
+        let mut y = [5, 6];
+        let mut z = Concat::new(x.as_mut_sqnc(), y.as_mut_sqnc()).unwrap();
+        let mut chunks = z.sqnc_chunks_mut(2).unwrap();
+        let mut a = chunks.next().unwrap();
+        let mut b = chunks.next().unwrap();
+        let mut c = chunks.next().unwrap();
+        assert!(chunks.next().is_none());
+        a.iter_mut().zip(b.iter_mut()).for_each(|(a, b)| *a += *b);
+        *c.first_mut().unwrap() += *b.last_mut().unwrap();
+        assert_eq!(x, [6, 8, 4]);
+        assert_eq!(y, [5, 11]);
+    }
+
     #[test]
     fn into_iter() {
         assert!(Concat::new(2..5, 5..7).unwrap().into_iter().eq(2..7))
     }
+
+    #[test]
+    fn swap_reverse_rotate_fill() {
+        let mut x = [0, 1];
+        let mut y = [2, 3, 4];
+        let mut z = Concat::new(x.as_mut_sqnc(), y.as_mut_sqnc()).unwrap();
+        assert_eq!(z.sqnc_swap(0, 4), Some(()));
+        assert_eq!(z.sqnc_swap(1, 5), None);
+        z.sqnc_reverse();
+        assert_eq!(z.sqnc_rotate_left(2), Some(()));
+        assert_eq!(z.sqnc_rotate_right(1), Some(()));
+        assert_eq!(x, [3, 2]);
+        assert_eq!(y, [1, 4, 0]);
+        let mut z = Concat::new(x.as_mut_sqnc(), y.as_mut_sqnc()).unwrap();
+        z.sqnc_fill(5);
+        assert_eq!(x, [5, 5]);
+        assert_eq!(y, [5, 5, 5]);
+    }
 }
@@ -0,0 +1,204 @@
+use crate::derive::{IntoIter, Iter};
+use crate::traits::*;
+
+/// A sequence that repeats a single value.
+///
+/// This struct is created by [`constant()`]. See its documentation for more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Constant<T> {
+    value: T,
+    len: usize,
+}
+
+/// Returns a sequence of length `len` with all elements equal to `value`.
+///
+/// The elements are clones of `value`. To obtain a sequence of references
+/// instead, pass a reference as `value`.
+///
+/// # Examples
+///
+/// ```
+/// use sqnc::Sequence;
+///
+/// let x = sqnc::constant(2, 3);
+/// assert_eq!(x.len(), 3);
+/// assert_eq!(x.get(1), Some(2));
+/// assert!(x.iter().eq([2, 2, 2]));
+/// ```
+///
+/// A constant sequence of indices can be used to select an element
+/// repeatedly:
+///
+/// ```
+/// use sqnc::Sequence;
+///
+/// let x = *b"abc";
+/// let y = x.select(sqnc::constant(1, 3)).unwrap();
+/// assert!(y.iter().eq(b"bbb"));
+/// ```
+#[inline]
+pub fn constant<T>(value: T, len: usize) -> Constant<T>
+where
+    T: Clone,
+{
+    Constant { value, len }
+}
+
+impl<T> Constant<T> {
+    /// Returns a reference to the repeated value.
+    #[inline]
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<'this, T> SequenceTypes<'this> for Constant<T>
+where
+    T: Clone,
+{
+    type Item = T;
+    type Iter = Iter<'this, Self>;
+}
+
+impl<T> Sequence for Constant<T>
+where
+    T: Clone,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<T> {
+        (index < self.len).then(|| self.value.clone())
+    }
+
+    #[inline]
+    fn iter(&self) -> Iter<'_, Self> {
+        self.into()
+    }
+
+    #[inline]
+    fn min<'a>(&'a self) -> Option<T>
+    where
+        <Self as SequenceTypes<'a>>::Item: Ord,
+    {
+        self.first()
+    }
+
+    #[inline]
+    fn max<'a>(&'a self) -> Option<T>
+    where
+        <Self as SequenceTypes<'a>>::Item: Ord,
+    {
+        self.first()
+    }
+}
+
+impl<T> IntoIterator for Constant<T>
+where
+    T: Clone,
+{
+    type Item = T;
+    type IntoIter = IntoIter<Self>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::constant;
+    use crate::traits::*;
+
+    #[test]
+    fn len() {
+        assert_eq!(constant('a', 3).len(), 3);
+        assert_eq!(constant('a', 0).len(), 0);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(!constant('a', 3).is_empty());
+        assert!(constant('a', 0).is_empty());
+    }
+
+    #[test]
+    fn value() {
+        assert_eq!(constant('a', 0).value(), &'a');
+    }
+
+    #[test]
+    fn get() {
+        let x = constant('a', 2);
+        assert_eq!(x.get(0), Some('a'));
+        assert_eq!(x.get(1), Some('a'));
+        assert_eq!(x.get(2), None);
+    }
+
+    #[test]
+    fn rget() {
+        let x = constant('a', 2);
+        assert_eq!(x.rget(1), Some('a'));
+        assert_eq!(x.rget(2), None);
+    }
+
+    #[test]
+    fn get_ref() {
+        let v = [1, 2];
+        let x = constant(&v, 2);
+        assert_eq!(x.get(1), Some(&v));
+    }
+
+    #[test]
+    fn iter() {
+        assert!(constant('a', 3).iter().eq(['a', 'a', 'a']));
+        assert!(constant('a', 0).iter().eq([]));
+    }
+
+    #[test]
+    fn into_iter() {
+        assert!(constant('a', 2).into_iter().eq(['a', 'a']));
+    }
+
+    #[test]
+    fn min_max() {
+        assert_eq!(constant('a', 2).min(), Some('a'));
+        assert_eq!(constant('a', 2).max(), Some('a'));
+        assert_eq!(constant('a', 0).min(), None);
+        assert_eq!(constant('a', 0).max(), None);
+    }
+
+    #[test]
+    fn select() {
+        let x = *b"abc";
+        let y = x.select(constant(2, 2)).unwrap();
+        assert!(y.iter().eq(b"cc"));
+        assert!(x.select(constant(3, 2)).is_none());
+        assert!(x.select(constant(3, 0)).is_some());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_test::{assert_tokens, Token};
+        assert_tokens(
+            &constant('a', 3),
+            &[
+                Token::Struct {
+                    name: "Constant",
+                    len: 2,
+                },
+                Token::Str("value"),
+                Token::Char('a'),
+                Token::Str("len"),
+                Token::U64(3),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
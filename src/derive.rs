@@ -53,6 +53,19 @@ where
     }
 }
 
+impl<'s, S> Iter<'s, S>
+where
+    S: Sequence,
+{
+    /// Returns an iterator over the elements with indices in `index`.
+    ///
+    /// The range must be a subrange of `0..seq.len()`.
+    #[inline]
+    pub(crate) fn with_range(seq: &'s S, index: Range<usize>) -> Self {
+        Self { seq, index }
+    }
+}
+
 impl<'s, S> Iterator for Iter<'s, S>
 where
     S: Sequence,
@@ -64,6 +77,11 @@ where
         self.seq.get(self.index.next()?)
     }
 
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.seq.get(self.index.nth(n)?)
+    }
+
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
         self.index.size_hint()
@@ -159,6 +177,11 @@ where
         self.seq.get(self.index.next()?)
     }
 
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.seq.get(self.index.nth(n)?)
+    }
+
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
         self.index.size_hint()
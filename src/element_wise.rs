@@ -0,0 +1,188 @@
+use crate::traits::*;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// Wrapper that compares and hashes a [`Sequence`] by its elements.
+///
+/// The adaptors in this crate derive [`PartialEq`] by comparing their parts,
+/// e.g. the indices and the parent sequence of a [`Select`][`crate::Select`],
+/// instead of their elements. [`ElementWise`] implements [`PartialEq`],
+/// [`Eq`], [`PartialOrd`], [`Ord`] and [`Hash`] by comparing and hashing the
+/// elements using [`Sequence::seq_eq()`], [`Sequence::seq_partial_cmp()`],
+/// [`Sequence::seq_cmp()`] and [`Sequence::seq_hash()`], such that sequences
+/// can be used as keys of a map or set. To compare sequences of different
+/// types, use these methods directly.
+///
+/// # Examples
+///
+/// Counting the distinct windows of a sequence:
+///
+/// ```
+/// use sqnc::{ElementWise, Sequence};
+/// use std::collections::HashSet;
+///
+/// let x = *b"abcabd";
+/// let y = x.sqnc_windows(2).unwrap();
+/// let z: HashSet<_> = y.iter().map(ElementWise::new).collect();
+/// assert_eq!(z.len(), 4); // `ab`, `bc`, `ca` and `bd`
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ElementWise<S>(S);
+
+impl<S> ElementWise<S>
+where
+    S: Sequence,
+{
+    /// Wraps a sequence.
+    #[inline]
+    pub fn new(sequence: S) -> Self {
+        Self(sequence)
+    }
+}
+
+impl<S> ElementWise<S> {
+    /// Returns the wrapped sequence.
+    #[inline]
+    pub fn unwrap(self) -> S {
+        self.0
+    }
+}
+
+impl<S> AsRef<S> for ElementWise<S> {
+    #[inline]
+    fn as_ref(&self) -> &S {
+        &self.0
+    }
+}
+
+impl<S> PartialEq for ElementWise<S>
+where
+    S: Sequence,
+    for<'a> <S as SequenceTypes<'a>>::Item: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        // Both sequences are borrowed for the same lifetime, hence the
+        // elements have the same type.
+        let (a, b): (&S, &S) = (&self.0, &other.0);
+        a.seq_eq(b)
+    }
+}
+
+impl<S> Eq for ElementWise<S>
+where
+    S: Sequence,
+    for<'a> <S as SequenceTypes<'a>>::Item: Eq,
+{
+}
+
+impl<S> PartialOrd for ElementWise<S>
+where
+    S: Sequence,
+    for<'a> <S as SequenceTypes<'a>>::Item: PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let (a, b): (&S, &S) = (&self.0, &other.0);
+        a.seq_partial_cmp(b)
+    }
+}
+
+impl<S> Ord for ElementWise<S>
+where
+    S: Sequence,
+    for<'a> <S as SequenceTypes<'a>>::Item: Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b): (&S, &S) = (&self.0, &other.0);
+        a.seq_cmp(b)
+    }
+}
+
+impl<S> Hash for ElementWise<S>
+where
+    S: Sequence,
+    for<'a> <S as SequenceTypes<'a>>::Item: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.seq_hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ElementWise;
+    use crate::traits::*;
+    use core::cmp::Ordering;
+
+    #[test]
+    fn eq() {
+        let x = *b"abcd";
+        let y = ElementWise::new(x.select([1, 2].copied()).unwrap());
+        assert_eq!(y, ElementWise::new(x.select([1, 2].copied()).unwrap()));
+        assert_ne!(y, ElementWise::new(x.select([2, 3].copied()).unwrap()));
+        assert_ne!(
+            ElementWise::new(x.select(1..3).unwrap()),
+            ElementWise::new(x.select(1..4).unwrap())
+        );
+    }
+
+    #[test]
+    fn eq_structurally_different() {
+        let x = *b"abz";
+        let y = *b"zab";
+        let z = x.select(0..2).unwrap();
+        let w = y.select(1..3).unwrap();
+        assert_ne!(z, w);
+        assert_eq!(ElementWise::new(z), ElementWise::new(w));
+    }
+
+    #[test]
+    fn partial_cmp() {
+        let x = [1.0, 2.0, 1.0, 3.0, f64::NAN];
+        let y = ElementWise::new(x.select([0, 1].copied()).unwrap());
+        let z = |i: [usize; 2]| ElementWise::new(x.select(i.copied()).unwrap());
+        assert_eq!(y.partial_cmp(&z([2, 3])), Some(Ordering::Less));
+        assert_eq!(y.partial_cmp(&z([0, 0])), Some(Ordering::Greater));
+        assert_eq!(y.partial_cmp(&z([0, 4])), None);
+    }
+
+    #[test]
+    fn cmp() {
+        let x = *b"abcd";
+        let y = ElementWise::new(x.select([1, 2].copied()).unwrap());
+        let z = ElementWise::new(x.select([1, 3].copied()).unwrap());
+        assert_eq!(y.cmp(&z), Ordering::Less);
+        assert_eq!(z.cmp(&y), Ordering::Greater);
+        assert_eq!(y.cmp(&y), Ordering::Equal);
+        assert!(y < z);
+    }
+
+    #[test]
+    fn unwrap_as_ref() {
+        let x = ElementWise::new(2..4);
+        assert_eq!(x.as_ref(), &(2..4));
+        assert_eq!(x.unwrap(), 2..4);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash() {
+        extern crate std;
+        use std::collections::HashMap;
+
+        let x = *b"abcd";
+        let mut map = HashMap::new();
+        map.insert(ElementWise::new(x.select([1, 2].copied()).unwrap()), 1);
+        assert_eq!(
+            map.get(&ElementWise::new(x.select([1, 2].copied()).unwrap())),
+            Some(&1)
+        );
+        assert_eq!(
+            map.get(&ElementWise::new(x.select([2, 1].copied()).unwrap())),
+            None
+        );
+    }
+}
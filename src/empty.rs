@@ -0,0 +1,257 @@
+use crate::traits::*;
+use core::fmt;
+use core::iter;
+use core::marker::PhantomData;
+
+/// An empty sequence with elements of type `T`.
+///
+/// This struct is created by [`empty()`]. See its documentation for more.
+// The traits below are implemented manually, because deriving them would
+// needlessly require `T` to implement the derived traits.
+pub struct Empty<T>(PhantomData<fn() -> T>);
+
+/// Returns an empty sequence with elements of type `T`.
+///
+/// # Examples
+///
+/// ```
+/// use sqnc::Sequence;
+///
+/// let x = sqnc::empty::<char>();
+/// assert_eq!(x.len(), 0);
+/// assert_eq!(x.get(0), None);
+/// assert!(x.iter().next().is_none());
+/// ```
+///
+/// An empty sequence of indices can be used to create an empty selection:
+///
+/// ```
+/// use sqnc::Sequence;
+///
+/// let x = *b"abc";
+/// let y = x.select(sqnc::empty()).unwrap();
+/// assert!(y.is_empty());
+/// ```
+#[inline]
+pub const fn empty<T>() -> Empty<T> {
+    Empty(PhantomData)
+}
+
+impl<T> fmt::Debug for Empty<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Empty")
+    }
+}
+
+impl<T> Clone for Empty<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Empty<T> {}
+
+impl<T> Default for Empty<T> {
+    #[inline]
+    fn default() -> Self {
+        empty()
+    }
+}
+
+impl<T> PartialEq for Empty<T> {
+    #[inline]
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<T> Eq for Empty<T> {}
+
+impl<'this, T> SequenceTypes<'this> for Empty<T> {
+    type Item = T;
+    type Iter = iter::Empty<T>;
+}
+
+impl<'this, T> MutSequenceTypes<'this> for Empty<T> {
+    type MutItem = T;
+    type IterMut = iter::Empty<T>;
+}
+
+impl<T> Sequence for Empty<T> {
+    #[inline]
+    fn len(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn get(&self, _index: usize) -> Option<T> {
+        None
+    }
+
+    #[inline]
+    fn rget(&self, _rindex: usize) -> Option<T> {
+        None
+    }
+
+    #[inline]
+    fn first(&self) -> Option<T> {
+        None
+    }
+
+    #[inline]
+    fn last(&self) -> Option<T> {
+        None
+    }
+
+    #[inline]
+    fn iter(&self) -> iter::Empty<T> {
+        iter::empty()
+    }
+
+    #[inline]
+    fn min<'a>(&'a self) -> Option<T>
+    where
+        <Self as SequenceTypes<'a>>::Item: Ord,
+    {
+        None
+    }
+
+    #[inline]
+    fn max<'a>(&'a self) -> Option<T>
+    where
+        <Self as SequenceTypes<'a>>::Item: Ord,
+    {
+        None
+    }
+}
+
+impl<T> MutSequence for Empty<T> {
+    #[inline]
+    fn get_mut(&mut self, _index: usize) -> Option<T> {
+        None
+    }
+
+    #[inline]
+    fn iter_mut(&mut self) -> iter::Empty<T> {
+        iter::empty()
+    }
+}
+
+// SAFETY: An empty sequence has no elements that could alias.
+unsafe impl<T> UniqueSequence for Empty<T> {}
+
+// SAFETY: See above.
+unsafe impl<T> UniqueMutSequence for Empty<T> {}
+
+impl<T> IntoIterator for Empty<T> {
+    type Item = T;
+    type IntoIter = iter::Empty<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        iter::empty()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for Empty<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_unit_struct("Empty")
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Empty<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct EmptyVisitor<T>(PhantomData<fn() -> T>);
+
+        impl<'de, T> serde::de::Visitor<'de> for EmptyVisitor<T> {
+            type Value = Empty<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("unit struct Empty")
+            }
+
+            fn visit_unit<E>(self) -> Result<Empty<T>, E> {
+                Ok(empty())
+            }
+        }
+
+        deserializer.deserialize_unit_struct("Empty", EmptyVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::empty;
+    use crate::traits::*;
+
+    #[test]
+    fn len() {
+        assert_eq!(empty::<char>().len(), 0);
+        assert!(empty::<char>().is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let mut x = empty::<char>();
+        assert_eq!(x.get(0), None);
+        assert_eq!(x.rget(0), None);
+        assert_eq!(x.first(), None);
+        assert_eq!(x.last(), None);
+        assert_eq!(x.get_mut(0), None);
+    }
+
+    #[test]
+    fn iter() {
+        let mut x = empty::<char>();
+        assert_eq!(x.iter().len(), 0);
+        assert_eq!(x.iter_mut().len(), 0);
+        assert_eq!(x.into_iter().len(), 0);
+    }
+
+    #[test]
+    fn min_max() {
+        assert_eq!(empty::<char>().min(), None);
+        assert_eq!(empty::<char>().max(), None);
+    }
+
+    #[test]
+    fn select() {
+        let x = *b"abc";
+        assert!(x.select(empty()).unwrap().iter().eq(b""));
+    }
+
+    #[test]
+    fn concat() {
+        let x = empty().concat(['a', 'b'].copied()).unwrap();
+        assert!(x.iter().eq(['a', 'b']));
+    }
+
+    #[test]
+    fn copy_eq() {
+        struct NotCopy;
+        let x = empty::<NotCopy>();
+        let y = x;
+        assert_eq!(x, y);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_test::{assert_tokens, Token};
+        assert_tokens(&empty::<char>(), &[Token::UnitStruct { name: "Empty" }]);
+    }
+}
@@ -0,0 +1,231 @@
+use crate::traits::*;
+use core::iter;
+
+/// A sequence that pairs the elements of a sequence with their indices.
+///
+/// This struct is created by [`Sequence::sqnc_enumerate()`]. See its documentation
+/// for more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Enumerate<Seq>(Seq);
+
+impl<Seq> Enumerate<Seq> {
+    #[inline]
+    pub(crate) fn new(seq: Seq) -> Self {
+        Self(seq)
+    }
+}
+
+impl<'this, Seq> SequenceTypes<'this> for Enumerate<Seq>
+where
+    Seq: SequenceTypes<'this>,
+{
+    type Item = (usize, Seq::Item);
+    type Iter = iter::Enumerate<Seq::Iter>;
+}
+
+impl<'this, Seq> MutSequenceTypes<'this> for Enumerate<Seq>
+where
+    Seq: MutSequenceTypes<'this>,
+{
+    type MutItem = (usize, Seq::MutItem);
+    type IterMut = iter::Enumerate<Seq::IterMut>;
+}
+
+impl<Seq> Sequence for Enumerate<Seq>
+where
+    Seq: Sequence,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<<Self as SequenceTypes<'_>>::Item> {
+        Some((index, self.0.get(index)?))
+    }
+
+    #[inline]
+    fn rget(&self, rindex: usize) -> Option<<Self as SequenceTypes<'_>>::Item> {
+        let index = self.0.len().checked_sub(rindex)?.checked_sub(1)?;
+        Some((index, self.0.get(index)?))
+    }
+
+    #[inline]
+    fn first(&self) -> Option<<Self as SequenceTypes<'_>>::Item> {
+        Some((0, self.0.first()?))
+    }
+
+    #[inline]
+    fn iter(&self) -> <Self as SequenceTypes<'_>>::Iter {
+        self.0.iter().enumerate()
+    }
+}
+
+impl<Seq> MutSequence for Enumerate<Seq>
+where
+    Seq: MutSequence,
+{
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<<Self as MutSequenceTypes<'_>>::MutItem> {
+        Some((index, self.0.get_mut(index)?))
+    }
+
+    #[inline]
+    fn rget_mut(&mut self, rindex: usize) -> Option<<Self as MutSequenceTypes<'_>>::MutItem> {
+        let index = self.0.len().checked_sub(rindex)?.checked_sub(1)?;
+        Some((index, self.0.get_mut(index)?))
+    }
+
+    #[inline]
+    fn first_mut(&mut self) -> Option<<Self as MutSequenceTypes<'_>>::MutItem> {
+        Some((0, self.0.first_mut()?))
+    }
+
+    #[inline]
+    fn iter_mut(&mut self) -> <Self as MutSequenceTypes<'_>>::IterMut {
+        self.0.iter_mut().enumerate()
+    }
+}
+
+impl<Seq, Item> IntoIterator for Enumerate<Seq>
+where
+    Seq: Sequence + for<'a> SequenceTypes<'a, Item = Item> + IntoIterator<Item = Item>,
+{
+    type Item = (usize, Item);
+    type IntoIter = iter::Enumerate<Seq::IntoIter>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter().enumerate()
+    }
+}
+
+// SAFETY: The elements of `Enumerate` are pairs of an index and an element of
+// the parent sequence. If the parent is unique, the pairs are unique.
+unsafe impl<Seq> UniqueSequence for Enumerate<Seq> where Seq: UniqueSequence {}
+
+// SAFETY: See above.
+unsafe impl<Seq> UniqueMutSequence for Enumerate<Seq> where Seq: UniqueMutSequence {}
+
+#[cfg(test)]
+mod tests {
+    use super::Enumerate;
+    use crate::traits::*;
+
+    #[test]
+    fn len() {
+        assert_eq!(Enumerate::new(*b"abc").len(), 3);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(!Enumerate::new(*b"abc").is_empty());
+        assert!(Enumerate::new(*b"").is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let x = Enumerate::new(*b"abc");
+        assert_eq!(x.get(0), Some((0, &b'a')));
+        assert_eq!(x.get(1), Some((1, &b'b')));
+        assert_eq!(x.get(2), Some((2, &b'c')));
+        assert_eq!(x.get(3), None);
+    }
+
+    #[test]
+    fn rget() {
+        let x = Enumerate::new(*b"abc");
+        assert_eq!(x.rget(0), Some((2, &b'c')));
+        assert_eq!(x.rget(1), Some((1, &b'b')));
+        assert_eq!(x.rget(2), Some((0, &b'a')));
+        assert_eq!(x.rget(3), None);
+        assert_eq!(x.rget(usize::MAX), None);
+    }
+
+    #[test]
+    fn first() {
+        assert_eq!(Enumerate::new(*b"abc").first(), Some((0, &b'a')));
+        assert_eq!(Enumerate::new(*b"").first(), None);
+    }
+
+    #[test]
+    fn last() {
+        assert_eq!(Enumerate::new(*b"abc").last(), Some((2, &b'c')));
+        assert_eq!(Enumerate::new(*b"").last(), None);
+    }
+
+    #[test]
+    fn iter() {
+        let x = Enumerate::new(*b"abc");
+        assert!(x.iter().eq([(0, &b'a'), (1, &b'b'), (2, &b'c')]));
+    }
+
+    #[test]
+    fn rev_iter() {
+        let x = Enumerate::new(*b"abc");
+        assert!(x.iter().rev().eq([(2, &b'c'), (1, &b'b'), (0, &b'a')]));
+    }
+
+    #[test]
+    fn get_mut() {
+        let mut x = [3, 4, 5];
+        let mut y = Enumerate::new(x.as_mut_sqnc());
+        if let Some((i, v)) = y.get_mut(1) {
+            *v += i;
+        }
+        assert!(y.get_mut(3).is_none());
+        assert_eq!(x, [3, 5, 5]);
+    }
+
+    #[test]
+    fn rget_mut() {
+        let mut x = [3, 4, 5];
+        let mut y = Enumerate::new(x.as_mut_sqnc());
+        if let Some((i, v)) = y.rget_mut(0) {
+            *v += i;
+        }
+        assert!(y.rget_mut(3).is_none());
+        assert_eq!(x, [3, 4, 7]);
+    }
+
+    #[test]
+    fn first_mut() {
+        let mut x = [3, 4, 5];
+        let mut y = Enumerate::new(x.as_mut_sqnc());
+        if let Some((i, v)) = y.first_mut() {
+            *v = i;
+        }
+        assert_eq!(x, [0, 4, 5]);
+    }
+
+    #[test]
+    fn last_mut() {
+        let mut x = [3, 4, 5];
+        let mut y = Enumerate::new(x.as_mut_sqnc());
+        if let Some((i, v)) = y.last_mut() {
+            *v = i;
+        }
+        assert_eq!(x, [3, 4, 2]);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut x = [3, 4, 5];
+        Enumerate::new(x.as_mut_sqnc())
+            .iter_mut()
+            .for_each(|(i, v)| *v *= i);
+        assert_eq!(x, [0, 4, 10]);
+    }
+
+    #[test]
+    fn into_iter() {
+        let x = Enumerate::new(3..6);
+        assert!(x.into_iter().eq([(0, 3), (1, 4), (2, 5)]));
+    }
+}
@@ -0,0 +1,292 @@
+extern crate alloc;
+use crate::traits::*;
+use alloc::vec::Vec;
+use core::iter::{self, FusedIterator};
+
+/// A sequence that flattens a sequence of sequences.
+///
+/// This struct is created by [`Sequence::flatten()`]. See its documentation
+/// for more. Requires feature `alloc`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Flatten<Seq> {
+    seq: Seq,
+    // The cumulative lengths of the inner sequences, starting with zero.
+    offsets: Vec<usize>,
+}
+
+impl<Seq> Flatten<Seq>
+where
+    Seq: Sequence,
+    for<'a> <Seq as SequenceTypes<'a>>::Item: Sequence + IntoIterator,
+{
+    pub(crate) fn new(seq: Seq) -> Option<Self> {
+        let mut offsets = Vec::with_capacity(seq.len() + 1);
+        let mut offset: usize = 0;
+        offsets.push(offset);
+        for inner in seq.iter() {
+            offset = offset.checked_add(inner.len())?;
+            offsets.push(offset);
+        }
+        Some(Self { seq, offsets })
+    }
+
+    /// Returns the index of the inner sequence and the index into the inner
+    /// sequence for the given index or `None` if out of bounds.
+    #[inline]
+    fn locate(&self, index: usize) -> Option<(usize, usize)> {
+        if index < self.offsets[self.offsets.len() - 1] {
+            let outer = self.offsets.partition_point(|&offset| offset <= index) - 1;
+            Some((outer, index - self.offsets[outer]))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'this, Seq> SequenceTypes<'this> for Flatten<Seq>
+where
+    Seq: Sequence,
+    for<'a> <Seq as SequenceTypes<'a>>::Item: Sequence + IntoIterator,
+    for<'a> <<Seq as SequenceTypes<'a>>::Item as IntoIterator>::IntoIter: DoubleEndedIterator,
+{
+    type Item = <<Seq as SequenceTypes<'this>>::Item as IntoIterator>::Item;
+    type Iter = FlattenIter<iter::Flatten<<Seq as SequenceTypes<'this>>::Iter>>;
+}
+
+impl<'this, Seq> MutSequenceTypes<'this> for Flatten<Seq>
+where
+    Seq: MutSequence,
+    for<'a> <Seq as SequenceTypes<'a>>::Item: Sequence + IntoIterator,
+    for<'a> <<Seq as SequenceTypes<'a>>::Item as IntoIterator>::IntoIter: DoubleEndedIterator,
+    for<'a> <Seq as MutSequenceTypes<'a>>::MutItem: MutSequence + IntoIterator,
+    for<'a> <<Seq as MutSequenceTypes<'a>>::MutItem as IntoIterator>::IntoIter: DoubleEndedIterator,
+{
+    type MutItem = <<Seq as MutSequenceTypes<'this>>::MutItem as IntoIterator>::Item;
+    type IterMut = FlattenIter<iter::Flatten<<Seq as MutSequenceTypes<'this>>::IterMut>>;
+}
+
+impl<Seq> Sequence for Flatten<Seq>
+where
+    Seq: Sequence,
+    for<'a> <Seq as SequenceTypes<'a>>::Item: Sequence + IntoIterator,
+    for<'a> <<Seq as SequenceTypes<'a>>::Item as IntoIterator>::IntoIter: DoubleEndedIterator,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        // `offsets` contains at least one element.
+        self.offsets[self.offsets.len() - 1]
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<<Self as SequenceTypes<'_>>::Item> {
+        let (outer, inner) = self.locate(index)?;
+        self.seq.get(outer)?.into_iter().nth(inner)
+    }
+
+    #[inline]
+    fn iter(&self) -> <Self as SequenceTypes<'_>>::Iter {
+        FlattenIter {
+            iter: self.seq.iter().flatten(),
+            len: self.len(),
+        }
+    }
+}
+
+impl<Seq> MutSequence for Flatten<Seq>
+where
+    Seq: MutSequence,
+    for<'a> <Seq as SequenceTypes<'a>>::Item: Sequence + IntoIterator,
+    for<'a> <<Seq as SequenceTypes<'a>>::Item as IntoIterator>::IntoIter: DoubleEndedIterator,
+    for<'a> <Seq as MutSequenceTypes<'a>>::MutItem: MutSequence + IntoIterator,
+    for<'a> <<Seq as MutSequenceTypes<'a>>::MutItem as IntoIterator>::IntoIter: DoubleEndedIterator,
+{
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<<Self as MutSequenceTypes<'_>>::MutItem> {
+        let (outer, inner) = self.locate(index)?;
+        self.seq.get_mut(outer)?.into_iter().nth(inner)
+    }
+
+    #[inline]
+    fn iter_mut(&mut self) -> <Self as MutSequenceTypes<'_>>::IterMut {
+        let len = self.len();
+        FlattenIter {
+            iter: self.seq.iter_mut().flatten(),
+            len,
+        }
+    }
+}
+
+/// An iterator with known length that flattens an iterator of iterators.
+pub struct FlattenIter<Iter> {
+    iter: Iter,
+    len: usize,
+}
+
+impl<Iter> Iterator for FlattenIter<Iter>
+where
+    Iter: Iterator,
+{
+    type Item = Iter::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let value = self.iter.next()?;
+        self.len -= 1;
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<Iter> DoubleEndedIterator for FlattenIter<Iter>
+where
+    Iter: DoubleEndedIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let value = self.iter.next_back()?;
+        self.len -= 1;
+        Some(value)
+    }
+}
+
+impl<Iter> ExactSizeIterator for FlattenIter<Iter> where Iter: Iterator {}
+
+impl<Iter> FusedIterator for FlattenIter<Iter> where Iter: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::Flatten;
+    use crate::traits::*;
+    use crate::{jagged, Arange};
+
+    #[test]
+    fn new() {
+        assert!(Flatten::new(jagged([0, 2, 5].copied(), [0, 1, 2, 3, 4]).unwrap()).is_some());
+        let x = [usize::MAX, 2]
+            .copied()
+            .map(|n| Arange::from_range(0..n).unwrap());
+        assert!(Flatten::new(x).is_none());
+    }
+
+    #[test]
+    fn len() {
+        let x = jagged([0, 2, 2, 5].copied(), [0, 1, 2, 3, 4]).unwrap();
+        assert_eq!(Flatten::new(x).unwrap().len(), 5);
+        let y = jagged([0, 0, 0].copied(), [0; 0]).unwrap();
+        assert_eq!(Flatten::new(y).unwrap().len(), 0);
+        let z = jagged([0].copied(), [0; 0]).unwrap();
+        assert_eq!(Flatten::new(z).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn is_empty() {
+        let x = jagged([0, 0, 1].copied(), [0]).unwrap();
+        assert!(!Flatten::new(x).unwrap().is_empty());
+        let y = jagged([0, 0, 0].copied(), [0; 0]).unwrap();
+        assert!(Flatten::new(y).unwrap().is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let x = jagged([0, 2, 2, 5, 5].copied(), [0, 1, 5, 6, 7]).unwrap();
+        let y = Flatten::new(x).unwrap();
+        assert_eq!(y.get(0), Some(&0));
+        assert_eq!(y.get(1), Some(&1));
+        assert_eq!(y.get(2), Some(&5));
+        assert_eq!(y.get(4), Some(&7));
+        assert_eq!(y.get(5), None);
+    }
+
+    #[test]
+    fn rget() {
+        let x = jagged([0, 2, 2, 5, 5].copied(), [0, 1, 5, 6, 7]).unwrap();
+        let y = Flatten::new(x).unwrap();
+        assert_eq!(y.rget(0), Some(&7));
+        assert_eq!(y.rget(2), Some(&5));
+        assert_eq!(y.rget(3), Some(&1));
+        assert_eq!(y.rget(5), None);
+    }
+
+    #[test]
+    fn first() {
+        let x = jagged([0, 0, 2].copied(), [3, 4]).unwrap();
+        assert_eq!(Flatten::new(x).unwrap().first(), Some(&3));
+        let y = jagged([0, 0, 0].copied(), [0; 0]).unwrap();
+        assert_eq!(Flatten::new(y).unwrap().first(), None);
+    }
+
+    #[test]
+    fn last() {
+        let x = jagged([0, 2, 2].copied(), [2, 3]).unwrap();
+        assert_eq!(Flatten::new(x).unwrap().last(), Some(&3));
+        let y = jagged([0, 0, 0].copied(), [0; 0]).unwrap();
+        assert_eq!(Flatten::new(y).unwrap().last(), None);
+    }
+
+    #[test]
+    fn iter() {
+        let x = jagged([0, 2, 2, 5].copied(), [0, 1, 5, 6, 7]).unwrap();
+        let y = Flatten::new(x).unwrap();
+        assert!(y.iter().eq(&[0, 1, 5, 6, 7]));
+        assert!(y.iter().rev().eq(&[7, 6, 5, 1, 0]));
+    }
+
+    #[test]
+    fn iter_size_hint() {
+        let x = jagged([0, 2, 2, 5].copied(), [0, 1, 5, 6, 7]).unwrap();
+        let y = Flatten::new(x).unwrap();
+        let mut iter = y.iter();
+        assert_eq!(iter.size_hint(), (5, Some(5)));
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+    }
+
+    #[test]
+    fn get_mut() {
+        let x = jagged([0, 2, 4, 6].copied(), [0, 1, 2, 3, 4, 5]).unwrap();
+        let mut y = Flatten::new(x).unwrap();
+        *y.get_mut(1).unwrap() = 6;
+        *y.get_mut(4).unwrap() = 7;
+        assert!(y.get_mut(6).is_none());
+        assert!(y.iter().eq(&[0, 6, 2, 3, 7, 5]));
+    }
+
+    #[test]
+    fn iter_mut() {
+        let x = jagged([0, 2, 4, 6].copied(), [0, 1, 2, 3, 4, 5]).unwrap();
+        let mut y = Flatten::new(x).unwrap();
+        y.iter_mut().zip(6..).for_each(|(v, i)| *v = i);
+        assert!(y.iter().eq(&[6, 7, 8, 9, 10, 11]));
+    }
+
+    #[test]
+    fn chunks() {
+        let x = [0, 1, 2, 3, 4];
+        let y = Flatten::new(x.sqnc_chunks(2).unwrap()).unwrap();
+        assert_eq!(y.len(), 5);
+        assert_eq!(y.get(3), Some(&3));
+        assert!(y.iter().eq(&x));
+        assert!(y.iter().rev().eq(x.iter().rev()));
+    }
+
+    #[test]
+    fn arange() {
+        let x = [2usize, 0, 3]
+            .copied()
+            .map(|n| Arange::from_range(0..n).unwrap());
+        let y = Flatten::new(x).unwrap();
+        assert_eq!(y.get(3), Some(1));
+        assert!(y.iter().eq([0, 1, 0, 1, 2]));
+    }
+}
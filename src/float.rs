@@ -0,0 +1,78 @@
+#[cfg(feature = "std")]
+extern crate std;
+use core::ops::{Add, Div, Mul, Sub};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Primitive floating point types.
+///
+/// This trait is sealed and implemented for [`f32`] and [`f64`]. It provides
+/// the arithmetic needed by [`Linspace`] and [`Geomspace`].
+///
+/// [`Linspace`]: crate::Linspace
+/// [`Geomspace`]: crate::Geomspace
+pub trait Float:
+    sealed::Sealed
+    + Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    /// Returns zero.
+    fn zero() -> Self;
+
+    /// Returns `n` converted to `Self`, rounding to the nearest value.
+    fn from_usize(n: usize) -> Self;
+
+    /// Returns `true` if `self` is neither infinite nor NaN.
+    fn is_finite(self) -> bool;
+
+    /// Returns the natural logarithm of `self`.
+    #[cfg(feature = "std")]
+    fn ln(self) -> Self;
+
+    /// Returns `e^self`.
+    #[cfg(feature = "std")]
+    fn exp(self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($($t:ident),*) => {$(
+        impl sealed::Sealed for $t {}
+
+        impl Float for $t {
+            #[inline]
+            fn zero() -> Self {
+                0.0
+            }
+
+            #[inline]
+            fn from_usize(n: usize) -> Self {
+                n as $t
+            }
+
+            #[inline]
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+
+            #[cfg(feature = "std")]
+            #[inline]
+            fn ln(self) -> Self {
+                $t::ln(self)
+            }
+
+            #[cfg(feature = "std")]
+            #[inline]
+            fn exp(self) -> Self {
+                $t::exp(self)
+            }
+        }
+    )*};
+}
+
+impl_float!(f32, f64);
@@ -0,0 +1,195 @@
+use crate::traits::*;
+use core::fmt;
+
+/// Formats the elements of a sequence like a slice using [`fmt::Debug`].
+///
+/// This struct is created by [`Sequence::debug_list()`]. See its
+/// documentation for more.
+#[derive(Clone, Copy)]
+pub struct DebugList<'a, S: ?Sized> {
+    sequence: &'a S,
+    max_len: Option<usize>,
+}
+
+/// Formats the elements of a sequence like a slice using [`fmt::Display`].
+///
+/// This struct is created by [`Sequence::display()`]. See its documentation
+/// for more.
+#[derive(Clone, Copy)]
+pub struct DisplayList<'a, S: ?Sized> {
+    sequence: &'a S,
+    max_len: Option<usize>,
+}
+
+impl<'a, S: ?Sized> DebugList<'a, S> {
+    #[inline]
+    pub(crate) fn new(sequence: &'a S) -> Self {
+        Self {
+            sequence,
+            max_len: None,
+        }
+    }
+
+    /// Limits the number of formatted elements to `max_len`.
+    ///
+    /// If the sequence is longer than `max_len`, only the first and last
+    /// elements are formatted, separated by `...`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::Sequence;
+    ///
+    /// let x = 0..10;
+    /// assert_eq!(format!("{:?}", x.debug_list().truncate(4)), "[0, 1, ..., 8, 9]");
+    /// assert_eq!(format!("{:?}", x.debug_list().truncate(3)), "[0, 1, ..., 9]");
+    /// assert_eq!(format!("{:?}", x.debug_list().truncate(0)), "[...]");
+    /// ```
+    #[inline]
+    pub fn truncate(self, max_len: usize) -> Self {
+        Self {
+            max_len: Some(max_len),
+            ..self
+        }
+    }
+}
+
+impl<'a, S: ?Sized> DisplayList<'a, S> {
+    #[inline]
+    pub(crate) fn new(sequence: &'a S) -> Self {
+        Self {
+            sequence,
+            max_len: None,
+        }
+    }
+
+    /// Limits the number of formatted elements to `max_len`.
+    ///
+    /// If the sequence is longer than `max_len`, only the first and last
+    /// elements are formatted, separated by `...`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::Sequence;
+    ///
+    /// let x = *b"abcdef";
+    /// let y = Sequence::map(x, |c| *c as char);
+    /// assert_eq!(format!("{}", y.display().truncate(2)), "[a, ..., f]");
+    /// ```
+    #[inline]
+    pub fn truncate(self, max_len: usize) -> Self {
+        Self {
+            max_len: Some(max_len),
+            ..self
+        }
+    }
+}
+
+/// Adaptor that formats a [`fmt::Display`] value via [`fmt::Debug`].
+struct AsDisplay<T>(T);
+
+impl<T: fmt::Display> fmt::Debug for AsDisplay<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Formats the elements of a sequence as a list, after applying `entry`.
+fn fmt_list<'a, S, T>(
+    sequence: &'a S,
+    max_len: Option<usize>,
+    f: &mut fmt::Formatter<'_>,
+    entry: impl Fn(<S as SequenceTypes<'a>>::Item) -> T,
+) -> fmt::Result
+where
+    S: Sequence + ?Sized,
+    T: fmt::Debug,
+{
+    let len = sequence.len();
+    let mut list = f.debug_list();
+    match max_len {
+        Some(max_len) if len > max_len => {
+            let tail = max_len / 2;
+            let head = max_len - tail;
+            list.entries(sequence.iter().take(head).map(&entry));
+            list.entry(&format_args!("..."));
+            list.entries(sequence.iter().skip(len - tail).map(&entry));
+        }
+        _ => {
+            list.entries(sequence.iter().map(&entry));
+        }
+    }
+    list.finish()
+}
+
+impl<'a, S> fmt::Debug for DebugList<'a, S>
+where
+    S: Sequence + ?Sized,
+    for<'b> <S as SequenceTypes<'b>>::Item: fmt::Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_list(self.sequence, self.max_len, f, |item| item)
+    }
+}
+
+impl<'a, S> fmt::Display for DisplayList<'a, S>
+where
+    S: Sequence + ?Sized,
+    for<'b> <S as SequenceTypes<'b>>::Item: fmt::Display,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_list(self.sequence, self.max_len, f, AsDisplay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use crate::traits::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_list() {
+        let x = *b"abcd";
+        let y = x.select([3, 1].copied()).unwrap();
+        assert_eq!(format!("{:?}", y.debug_list()), "[100, 98]");
+        let z = Sequence::map(y, |c| *c as char);
+        assert_eq!(format!("{:?}", z.debug_list()), "['d', 'b']");
+        assert_eq!(format!("{:?}", (0..0).debug_list()), "[]");
+    }
+
+    #[test]
+    fn debug_list_pretty() {
+        assert_eq!(
+            format!("{:#?}", (0..5).debug_list().truncate(2)),
+            "[\n    0,\n    ...,\n    4,\n]"
+        );
+    }
+
+    #[test]
+    fn debug_list_truncate() {
+        let x = 0..5;
+        assert_eq!(
+            format!("{:?}", x.debug_list().truncate(5)),
+            "[0, 1, 2, 3, 4]"
+        );
+        assert_eq!(
+            format!("{:?}", x.debug_list().truncate(4)),
+            "[0, 1, ..., 3, 4]"
+        );
+        assert_eq!(format!("{:?}", x.debug_list().truncate(1)), "[0, ...]");
+        assert_eq!(format!("{:?}", x.debug_list().truncate(0)), "[...]");
+    }
+
+    #[test]
+    fn display() {
+        let x = ['a', 'b', 'c'];
+        assert_eq!(format!("{}", x.display()), "[a, b, c]");
+        assert_eq!(format!("{}", x.display().truncate(2)), "[a, ..., c]");
+        assert_eq!(format!("{:.1}", [1.25, 2.5].display()), "[1.2, 2.5]");
+    }
+}
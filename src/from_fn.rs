@@ -0,0 +1,152 @@
+use crate::derive::{IntoIter, Iter};
+use crate::traits::*;
+
+/// A sequence that calls a function for every element.
+///
+/// This struct is created by [`from_fn()`]. See its documentation for more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FromFn<F> {
+    len: usize,
+    f: F,
+}
+
+/// Returns a sequence of length `len` where element `i` is `f(i)`.
+///
+/// The function is called upon every element access.
+///
+/// # Examples
+///
+/// ```
+/// use sqnc::Sequence;
+///
+/// let x = sqnc::from_fn(4, |i| i * i);
+/// assert_eq!(x.get(3), Some(9));
+/// assert!(x.iter().eq([0, 1, 4, 9]));
+/// ```
+///
+/// Using a function pointer gives a nameable type that can be stored in a
+/// struct:
+///
+/// ```
+/// use sqnc::{FromFn, Sequence};
+///
+/// type Squares = FromFn<fn(usize) -> usize>;
+///
+/// struct Data {
+///     squares: Squares,
+/// }
+///
+/// let data = Data {
+///     squares: sqnc::from_fn(3, |i| i * i),
+/// };
+/// assert!(data.squares.iter().eq([0, 1, 4]));
+/// ```
+#[inline]
+pub fn from_fn<F, T>(len: usize, f: F) -> FromFn<F>
+where
+    F: Fn(usize) -> T,
+{
+    FromFn { len, f }
+}
+
+impl<'this, F, T> SequenceTypes<'this> for FromFn<F>
+where
+    F: Fn(usize) -> T,
+{
+    type Item = T;
+    type Iter = Iter<'this, Self>;
+}
+
+impl<F, T> Sequence for FromFn<F>
+where
+    F: Fn(usize) -> T,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<T> {
+        (index < self.len).then(|| (self.f)(index))
+    }
+
+    #[inline]
+    fn iter(&self) -> Iter<'_, Self> {
+        self.into()
+    }
+}
+
+impl<F, T> IntoIterator for FromFn<F>
+where
+    F: Fn(usize) -> T,
+{
+    type Item = T;
+    type IntoIter = IntoIter<Self>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::from_fn;
+    use crate::traits::*;
+
+    #[test]
+    fn len() {
+        assert_eq!(from_fn(3, |i| i + 1).len(), 3);
+        assert_eq!(from_fn(0, |i| i + 1).len(), 0);
+    }
+
+    #[test]
+    fn get() {
+        let x = from_fn(3, |i| i + 1);
+        assert_eq!(x.get(0), Some(1));
+        assert_eq!(x.get(2), Some(3));
+        assert_eq!(x.get(3), None);
+    }
+
+    #[test]
+    fn rget() {
+        let x = from_fn(3, |i| i + 1);
+        assert_eq!(x.rget(0), Some(3));
+        assert_eq!(x.rget(2), Some(1));
+        assert_eq!(x.rget(3), None);
+    }
+
+    #[test]
+    fn first_last() {
+        let x = from_fn(3, |i| i + 1);
+        assert_eq!(x.first(), Some(1));
+        assert_eq!(x.last(), Some(3));
+        let y = from_fn(0, |i| i + 1);
+        assert_eq!(y.first(), None);
+        assert_eq!(y.last(), None);
+    }
+
+    #[test]
+    fn iter() {
+        let x = from_fn(3, |i| i + 1);
+        assert!(x.iter().eq([1, 2, 3]));
+        assert!(x.iter().rev().eq([3, 2, 1]));
+        assert!(x.into_iter().eq([1, 2, 3]));
+    }
+
+    #[test]
+    fn select() {
+        let x = *b"abcd";
+        let y = x.select(from_fn(2, |i| 3 - i)).unwrap();
+        assert!(y.iter().eq(b"dc"));
+        assert!(x.select(from_fn(2, |i| 4 - i)).is_none());
+    }
+
+    #[test]
+    fn capture() {
+        let x = [1, 2, 3];
+        let y = from_fn(x.len(), |i| x[i] * 2);
+        assert!(y.iter().eq([2, 4, 6]));
+    }
+}
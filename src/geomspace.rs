@@ -0,0 +1,195 @@
+use crate::derive::Iter;
+use crate::float::Float;
+use crate::traits::*;
+
+/// A sequence of floating point numbers evenly spaced on a log scale.
+///
+/// Like NumPy's `geomspace`, the sequence is defined by the first and last
+/// element, whereas NumPy's `logspace` takes the exponents of the first and
+/// last element. The first and last element are exactly `start` and `stop`,
+/// respectively. Requires feature `std`.
+///
+/// # Examples
+///
+/// ```
+/// use sqnc::{Geomspace, Sequence};
+///
+/// let x = Geomspace::new(1.0f64, 1000.0, 4).unwrap();
+/// assert_eq!(x.first(), Some(1.0));
+/// assert!((x.get(1).unwrap() - 10.0).abs() < 1e-12);
+/// assert!((x.get(2).unwrap() - 100.0).abs() < 1e-12);
+/// assert_eq!(x.last(), Some(1000.0));
+///
+/// // The bounds must be positive and finite.
+/// assert!(Geomspace::new(0.0, 1.0, 4).is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Geomspace<T> {
+    start: T,
+    stop: T,
+    len: usize,
+}
+
+impl<T> Geomspace<T>
+where
+    T: Float,
+{
+    /// Returns a sequence of `len` numbers from `start` to `stop`, inclusive,
+    /// such that the ratio of two consecutive elements is constant.
+    ///
+    /// Returns `None` if `start` or `stop` is not positive and finite. If
+    /// `len` is one, the sequence consists of `start` only.
+    #[inline]
+    pub fn new(start: T, stop: T, len: usize) -> Option<Self> {
+        let valid = |v: T| v > T::zero() && v.is_finite();
+        if valid(start) && valid(stop) {
+            Some(Self { start, stop, len })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'this, T> SequenceTypes<'this> for Geomspace<T>
+where
+    T: Float,
+{
+    type Item = T;
+    type Iter = Iter<'this, Self>;
+}
+
+impl<T> Sequence for Geomspace<T>
+where
+    T: Float,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<T> {
+        if index >= self.len {
+            None
+        } else if index == 0 {
+            Some(self.start)
+        } else if index == self.len - 1 {
+            Some(self.stop)
+        } else {
+            let start = self.start.ln();
+            let step = (self.stop.ln() - start) / T::from_usize(self.len - 1);
+            Some((start + step * T::from_usize(index)).exp())
+        }
+    }
+
+    #[inline]
+    fn iter(&self) -> Iter<'_, Self> {
+        self.into()
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "Geomspace")]
+struct GeomspaceFields<T> {
+    start: T,
+    stop: T,
+    len: usize,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Geomspace<T>
+where
+    T: Float + serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let GeomspaceFields { start, stop, len } = GeomspaceFields::deserialize(deserializer)?;
+        Self::new(start, stop, len)
+            .ok_or_else(|| serde::de::Error::custom("the bounds are not positive and finite"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Geomspace;
+    use crate::traits::*;
+
+    fn approx(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-12 * b.abs()
+    }
+
+    #[test]
+    fn new() {
+        assert!(Geomspace::new(1.0, 2.0, 3).is_some());
+        assert!(Geomspace::new(0.0, 2.0, 3).is_none());
+        assert!(Geomspace::new(1.0, -2.0, 3).is_none());
+        assert!(Geomspace::new(f64::NAN, 2.0, 3).is_none());
+        assert!(Geomspace::new(1.0, f64::INFINITY, 3).is_none());
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(Geomspace::new(1.0, 2.0, 3).unwrap().len(), 3);
+        assert_eq!(Geomspace::new(1.0, 2.0, 0).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn get() {
+        let x = Geomspace::new(2.0, 0.02, 3).unwrap();
+        assert_eq!(x.get(0), Some(2.0));
+        assert!(approx(x.get(1).unwrap(), 0.2));
+        assert_eq!(x.get(2), Some(0.02));
+        assert_eq!(x.get(3), None);
+        let y = Geomspace::new(2.0f32, 3.0, 1).unwrap();
+        assert_eq!(y.get(0), Some(2.0));
+        assert_eq!(y.get(1), None);
+    }
+
+    #[test]
+    fn rget() {
+        let x = Geomspace::new(1.0, 1e4, 5).unwrap();
+        assert_eq!(x.rget(0), Some(1e4));
+        assert!(approx(x.rget(1).unwrap(), 1e3));
+        assert_eq!(x.rget(4), Some(1.0));
+        assert_eq!(x.rget(5), None);
+    }
+
+    #[test]
+    fn iter() {
+        let x = Geomspace::new(1.0, 1e4, 5).unwrap();
+        assert!(x
+            .iter()
+            .zip([1.0, 1e1, 1e2, 1e3, 1e4])
+            .all(|(a, b)| approx(a, b)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+        let tokens = |start| {
+            [
+                Token::Struct {
+                    name: "Geomspace",
+                    len: 3,
+                },
+                Token::Str("start"),
+                Token::F64(start),
+                Token::Str("stop"),
+                Token::F64(100.0),
+                Token::Str("len"),
+                Token::U64(3),
+                Token::StructEnd,
+            ]
+        };
+        assert_tokens(&Geomspace::new(1.0, 100.0, 3).unwrap(), &tokens(1.0));
+        assert_de_tokens_error::<Geomspace<f64>>(
+            &tokens(0.0),
+            "the bounds are not positive and finite",
+        );
+    }
+}
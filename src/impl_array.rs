@@ -85,6 +85,9 @@ impl<T, const N: usize> MutSequence for [T; N] {
 // unique indices.
 unsafe impl<T, const N: usize> UniqueSequence for [T; N] {}
 
+// SAFETY: See above. `[T; N]::get_mut()` does not modify the array.
+unsafe impl<T, const N: usize> UniqueMutSequence for [T; N] {}
+
 #[cfg(test)]
 mod tests {
     use crate::traits::*;
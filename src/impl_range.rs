@@ -1,5 +1,6 @@
+use crate::derive::Iter;
 use crate::traits::*;
-use core::ops::Range;
+use core::ops::{Range, RangeInclusive};
 
 impl<'this> SequenceTypes<'this> for Range<usize> {
     type Item = usize;
@@ -58,6 +59,74 @@ impl Sequence for Range<usize> {
 unsafe impl UniqueSequence for Range<usize> {}
 unsafe impl UniqueIterator for Range<usize> {}
 
+impl<'this> SequenceTypes<'this> for RangeInclusive<usize> {
+    type Item = usize;
+    type Iter = Iter<'this, Self>;
+}
+
+impl Sequence for RangeInclusive<usize> {
+    /// Returns the length of the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length exceeds [`usize::MAX`], i.e. for `0..=usize::MAX`.
+    #[inline]
+    fn len(&self) -> usize {
+        if self.is_empty() {
+            0
+        } else {
+            (self.end() - self.start())
+                .checked_add(1)
+                .expect("the length of the range exceeds usize::MAX")
+        }
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<usize> {
+        let value = self.start().checked_add(index)?;
+        (!self.is_empty() && value <= *self.end()).then_some(value)
+    }
+
+    #[inline]
+    fn first(&self) -> Option<usize> {
+        (!self.is_empty()).then_some(*self.start())
+    }
+
+    #[inline]
+    fn last(&self) -> Option<usize> {
+        (!self.is_empty()).then_some(*self.end())
+    }
+
+    #[inline]
+    fn iter(&self) -> Iter<'_, Self> {
+        self.into()
+    }
+
+    #[inline]
+    fn min<'a>(&'a self) -> Option<usize>
+    where
+        <Self as SequenceTypes<'a>>::Item: Ord,
+    {
+        self.first()
+    }
+
+    #[inline]
+    fn max<'a>(&'a self) -> Option<usize>
+    where
+        <Self as SequenceTypes<'a>>::Item: Ord,
+    {
+        self.last()
+    }
+}
+
+// SAFETY: `RangeInclusive` is strict monotonic increasing, hence unique.
+unsafe impl UniqueSequence for RangeInclusive<usize> {}
+
 #[cfg(test)]
 mod tests {
     use crate::traits::*;
@@ -108,4 +177,60 @@ mod tests {
         assert_eq!(Sequence::max(&(2..5)), Some(4));
         assert_eq!(Sequence::max(&(7..5)), None);
     }
+
+    #[test]
+    fn inclusive_len() {
+        assert_eq!(Sequence::len(&(2..=5)), 4);
+        assert_eq!(Sequence::len(&(5..=5)), 1);
+        assert_eq!(Sequence::len(&(7..=5)), 0);
+        assert_eq!(Sequence::len(&(1..=usize::MAX)), usize::MAX);
+    }
+
+    #[test]
+    #[should_panic]
+    fn inclusive_len_overflow() {
+        let _ = Sequence::len(&(0..=usize::MAX));
+    }
+
+    #[test]
+    fn inclusive_is_empty() {
+        assert!(!Sequence::is_empty(&(5..=5)));
+        assert!(Sequence::is_empty(&(7..=5)));
+        let mut x = 5..=5;
+        x.next();
+        assert!(Sequence::is_empty(&x));
+        assert_eq!(Sequence::len(&x), 0);
+    }
+
+    #[test]
+    fn inclusive_get() {
+        let x = 2..=4;
+        assert_eq!(Sequence::get(&x, 0), Some(2));
+        assert_eq!(Sequence::get(&x, 2), Some(4));
+        assert_eq!(Sequence::get(&x, 3), None);
+        assert_eq!(Sequence::get(&(7..=5), 0), None);
+        assert_eq!(Sequence::get(&(1..=usize::MAX), usize::MAX), None);
+    }
+
+    #[test]
+    fn inclusive_first_last() {
+        assert_eq!(Sequence::first(&(2..=4)), Some(2));
+        assert_eq!(Sequence::last(&(2..=4)), Some(4));
+        assert_eq!(Sequence::first(&(7..=5)), None);
+        assert_eq!(Sequence::last(&(7..=5)), None);
+    }
+
+    #[test]
+    fn inclusive_iter() {
+        assert!(Sequence::iter(&(2..=4)).eq([2, 3, 4]));
+        assert!(Sequence::iter(&(2..=4)).rev().eq([4, 3, 2]));
+        assert!(Sequence::iter(&(7..=5)).eq([]));
+    }
+
+    #[test]
+    fn inclusive_min_max() {
+        assert_eq!(Sequence::min(&(2..=4)), Some(2));
+        assert_eq!(Sequence::max(&(2..=4)), Some(4));
+        assert_eq!(Sequence::max(&(7..=5)), None);
+    }
 }
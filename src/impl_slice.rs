@@ -84,6 +84,9 @@ impl<T> MutSequence for [T] {
 // SAFETY: `[T]::get()` and `[T]::get_mut()` return unique references for
 // unique indices.
 unsafe impl<T> UniqueSequence for [T] {}
+
+// SAFETY: See above. `[T]::get_mut()` does not modify the slice.
+unsafe impl<T> UniqueMutSequence for [T] {}
 unsafe impl<'this, T> UniqueIterator for slice::Iter<'this, T> {}
 unsafe impl<'this, T> UniqueIterator for slice::IterMut<'this, T> {}
 
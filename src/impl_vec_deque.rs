@@ -66,10 +66,19 @@ impl<T> MutSequence for VecDeque<T> {
     }
 }
 
+// SAFETY: `VecDeque::get()` and `VecDeque::get_mut()` return unique
+// references for unique indices and `VecDeque::get_mut()` does not modify the
+// deque.
+unsafe impl<T> UniqueSequence for VecDeque<T> {}
+unsafe impl<T> UniqueMutSequence for VecDeque<T> {}
+unsafe impl<'this, T> UniqueIterator for vec_deque::Iter<'this, T> {}
+unsafe impl<'this, T> UniqueIterator for vec_deque::IterMut<'this, T> {}
+
 #[cfg(test)]
 mod tests {
     extern crate alloc;
     use crate::traits::*;
+    use crate::Permutation;
     use alloc::collections::VecDeque;
 
     #[test]
@@ -167,4 +176,43 @@ mod tests {
         MutSequence::iter_mut(&mut x).for_each(|e| *e += 3);
         assert_eq!(x, VecDeque::from([5, 6, 7]));
     }
+
+    #[test]
+    fn select_iter_mut() {
+        let mut x = VecDeque::from([2, 3, 4, 5]);
+        x.as_mut_sqnc()
+            .select(Sequence::rev(1..3))
+            .unwrap()
+            .iter_mut()
+            .for_each(|e| *e += 3);
+        assert_eq!(x, VecDeque::from([2, 6, 7, 5]));
+    }
+
+    #[test]
+    fn select_split_at_mut() {
+        let mut x = VecDeque::from([3, 4, 5]);
+        x.push_front(2);
+        let p = Permutation::new([3, 0, 2, 1].copied()).unwrap();
+        let mut y = x.as_mut_sqnc().select(p).unwrap();
+        let (mut a, mut b) = y.sqnc_split_at_mut(2).unwrap();
+        a.iter_mut().zip(b.iter_mut()).for_each(|(a, b)| *a += *b);
+        assert_eq!(x, VecDeque::from([5, 3, 4, 9]));
+    }
+
+    #[test]
+    fn swap_reverse_rotate_fill() {
+        let mut x = VecDeque::from([2, 3, 4]);
+        x.push_front(1);
+        x.push_front(0);
+        assert_eq!(x.sqnc_swap(0, 4), Some(()));
+        assert!(x.iter().eq(&[4, 1, 2, 3, 0]));
+        x.sqnc_reverse();
+        assert!(x.iter().eq(&[0, 3, 2, 1, 4]));
+        assert_eq!(x.sqnc_rotate_left(2), Some(()));
+        assert!(x.iter().eq(&[2, 1, 4, 0, 3]));
+        assert_eq!(x.sqnc_rotate_right(2), Some(()));
+        assert!(x.iter().eq(&[0, 3, 2, 1, 4]));
+        x.sqnc_fill(5);
+        assert!(x.iter().eq(&[5, 5, 5, 5, 5]));
+    }
 }
@@ -0,0 +1,94 @@
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Primitive integer types.
+///
+/// This trait is sealed and implemented for all primitive integer types. It
+/// provides the arithmetic needed by [`Arange`].
+///
+/// [`Arange`]: crate::Arange
+pub trait Integer: sealed::Sealed + Copy + Ord {
+    /// The integer one.
+    const ONE: Self;
+
+    /// Returns the number of integers in `self..other` or `None` if the
+    /// number exceeds [`usize::MAX`].
+    fn checked_distance(self, other: Self) -> Option<usize>;
+
+    /// Returns `self + step * index` or `None` if overflow occurs.
+    fn checked_step(self, step: Self, index: usize) -> Option<Self>;
+
+    /// Returns `self + step * index`, wrapping around at the boundaries.
+    fn wrapping_step(self, step: Self, index: usize) -> Self;
+
+    /// Returns `true` if `self` is zero.
+    fn is_zero(self) -> bool;
+
+    /// Returns `true` if `self` is larger than zero.
+    fn is_positive(self) -> bool;
+}
+
+macro_rules! impl_integer {
+    (@common) => {
+        const ONE: Self = 1;
+
+        #[inline]
+        fn checked_distance(self, other: Self) -> Option<usize> {
+            if self < other {
+                usize::try_from(other.abs_diff(self)).ok()
+            } else {
+                Some(0)
+            }
+        }
+
+        #[inline]
+        fn wrapping_step(self, step: Self, index: usize) -> Self {
+            // Truncating `index` is harmless, since the result is computed
+            // modulo the size of the integer type anyway.
+            self.wrapping_add(step.wrapping_mul(index as Self))
+        }
+
+        #[inline]
+        fn is_zero(self) -> bool {
+            self == 0
+        }
+
+        #[inline]
+        fn is_positive(self) -> bool {
+            self > 0
+        }
+    };
+    (unsigned $($t:ty),*) => {$(
+        impl sealed::Sealed for $t {}
+
+        impl Integer for $t {
+            impl_integer!(@common);
+
+            #[inline]
+            fn checked_step(self, step: Self, index: usize) -> Option<Self> {
+                self.checked_add(step.checked_mul(<$t>::try_from(index).ok()?)?)
+            }
+        }
+    )*};
+    (signed $($t:ty: $u:ty),*) => {$(
+        impl sealed::Sealed for $t {}
+
+        impl Integer for $t {
+            impl_integer!(@common);
+
+            #[inline]
+            fn checked_step(self, step: Self, index: usize) -> Option<Self> {
+                let span = step.unsigned_abs().checked_mul(<$u>::try_from(index).ok()?)?;
+                if step > 0 {
+                    self.checked_add_unsigned(span)
+                } else {
+                    self.checked_sub_unsigned(span)
+                }
+            }
+        }
+    )*};
+}
+
+impl_integer!(unsigned u8, u16, u32, u64, u128, usize);
+impl_integer!(signed i8: u8, i16: u16, i32: u32, i64: u64, i128: u128, isize: usize);
@@ -0,0 +1,414 @@
+use crate::traits::*;
+use core::iter::FusedIterator;
+
+/// A sequence that alternates between the elements of two sequences.
+///
+/// This struct is created by [`Sequence::interleave()`]. See its documentation
+/// for more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interleave<Seq0, Seq1>(Seq0, Seq1);
+
+impl<Seq0, Seq1> Interleave<Seq0, Seq1>
+where
+    Seq0: Sequence,
+    Seq1: Sequence,
+{
+    #[inline]
+    pub(crate) fn new(seq0: Seq0, seq1: Seq1) -> Option<Self> {
+        if seq0.len() == seq1.len() && seq0.len().checked_mul(2).is_some() {
+            Some(Self(seq0, seq1))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'this, Seq0, Seq1, Item> SequenceTypes<'this> for Interleave<Seq0, Seq1>
+where
+    Seq0: SequenceTypes<'this, Item = Item>,
+    Seq1: SequenceTypes<'this, Item = Item>,
+{
+    type Item = Item;
+    type Iter = InterleaveIter<Seq0::Iter, Seq1::Iter>;
+}
+
+impl<'this, Seq0, Seq1, Item, MutItem> MutSequenceTypes<'this> for Interleave<Seq0, Seq1>
+where
+    Seq0: SequenceTypes<'this, Item = Item> + MutSequenceTypes<'this, MutItem = MutItem>,
+    Seq1: SequenceTypes<'this, Item = Item> + MutSequenceTypes<'this, MutItem = MutItem>,
+{
+    type MutItem = MutItem;
+    type IterMut = InterleaveIter<Seq0::IterMut, Seq1::IterMut>;
+}
+
+impl<Seq0, Seq1> Sequence for Interleave<Seq0, Seq1>
+where
+    Seq0: Sequence,
+    Seq1: Sequence + for<'a> SequenceTypes<'a, Item = <Seq0 as SequenceTypes<'a>>::Item>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len() * 2
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<<Self as SequenceTypes<'_>>::Item> {
+        if index % 2 == 0 {
+            self.0.get(index / 2)
+        } else {
+            self.1.get(index / 2)
+        }
+    }
+
+    #[inline]
+    fn rget(&self, rindex: usize) -> Option<<Self as SequenceTypes<'_>>::Item> {
+        if rindex % 2 == 0 {
+            self.1.rget(rindex / 2)
+        } else {
+            self.0.rget(rindex / 2)
+        }
+    }
+
+    #[inline]
+    fn first(&self) -> Option<<Self as SequenceTypes<'_>>::Item> {
+        self.0.first()
+    }
+
+    #[inline]
+    fn last(&self) -> Option<<Self as SequenceTypes<'_>>::Item> {
+        self.1.last()
+    }
+
+    #[inline]
+    fn iter(&self) -> <Self as SequenceTypes<'_>>::Iter {
+        InterleaveIter::new(self.0.iter(), self.1.iter())
+    }
+
+    #[inline]
+    fn min<'a>(&'a self) -> Option<<Self as SequenceTypes<'a>>::Item>
+    where
+        <Self as SequenceTypes<'a>>::Item: Ord,
+    {
+        self.0.min().min(self.1.min())
+    }
+
+    #[inline]
+    fn max<'a>(&'a self) -> Option<<Self as SequenceTypes<'a>>::Item>
+    where
+        <Self as SequenceTypes<'a>>::Item: Ord,
+    {
+        self.0.max().max(self.1.max())
+    }
+}
+
+impl<Seq0, Seq1> MutSequence for Interleave<Seq0, Seq1>
+where
+    Seq0: MutSequence,
+    Seq1: MutSequence
+        + for<'a> SequenceTypes<'a, Item = <Seq0 as SequenceTypes<'a>>::Item>
+        + for<'a> MutSequenceTypes<'a, MutItem = <Seq0 as MutSequenceTypes<'a>>::MutItem>,
+{
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<<Self as MutSequenceTypes<'_>>::MutItem> {
+        if index % 2 == 0 {
+            self.0.get_mut(index / 2)
+        } else {
+            self.1.get_mut(index / 2)
+        }
+    }
+
+    #[inline]
+    fn rget_mut(&mut self, rindex: usize) -> Option<<Self as MutSequenceTypes<'_>>::MutItem> {
+        if rindex % 2 == 0 {
+            self.1.rget_mut(rindex / 2)
+        } else {
+            self.0.rget_mut(rindex / 2)
+        }
+    }
+
+    #[inline]
+    fn first_mut(&mut self) -> Option<<Self as MutSequenceTypes<'_>>::MutItem> {
+        self.0.first_mut()
+    }
+
+    #[inline]
+    fn last_mut(&mut self) -> Option<<Self as MutSequenceTypes<'_>>::MutItem> {
+        self.1.last_mut()
+    }
+
+    #[inline]
+    fn iter_mut(&mut self) -> <Self as MutSequenceTypes<'_>>::IterMut {
+        InterleaveIter::new(self.0.iter_mut(), self.1.iter_mut())
+    }
+}
+
+// SAFETY: `Seq0` and `Seq1` are different fields of `Interleave`, hence the
+// mutable elements of `Seq0` don't alias those of `Seq1`. If both parts have
+// unique mutable elements, so does the interleaved sequence.
+unsafe impl<Seq0, Seq1> UniqueMutSequence for Interleave<Seq0, Seq1>
+where
+    Seq0: UniqueMutSequence,
+    Seq1: UniqueMutSequence
+        + for<'a> SequenceTypes<'a, Item = <Seq0 as SequenceTypes<'a>>::Item>
+        + for<'a> MutSequenceTypes<'a, MutItem = <Seq0 as MutSequenceTypes<'a>>::MutItem>,
+{
+}
+
+impl<Seq0, Seq1, Item> IntoIterator for Interleave<Seq0, Seq1>
+where
+    Seq0: Sequence + for<'a> SequenceTypes<'a, Item = Item> + IntoIterator<Item = Item>,
+    Seq1: Sequence + for<'a> SequenceTypes<'a, Item = Item> + IntoIterator<Item = Item>,
+    Seq0::IntoIter: FusedIterator,
+    Seq1::IntoIter: FusedIterator,
+{
+    type Item = Item;
+    type IntoIter = InterleaveIter<Seq0::IntoIter, Seq1::IntoIter>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        InterleaveIter::new(self.0.into_iter(), self.1.into_iter())
+    }
+}
+
+/// An iterator that alternates between two iterators of the same length.
+///
+/// The front of the iterator starts with the first iterator, the back with the
+/// second iterator.
+pub struct InterleaveIter<Iter0, Iter1> {
+    iter0: Iter0,
+    iter1: Iter1,
+    next_from_1: bool,
+    next_back_from_0: bool,
+}
+
+impl<Iter0, Iter1> InterleaveIter<Iter0, Iter1> {
+    #[inline]
+    fn new(iter0: Iter0, iter1: Iter1) -> Self {
+        Self {
+            iter0,
+            iter1,
+            next_from_1: false,
+            next_back_from_0: false,
+        }
+    }
+}
+
+impl<Iter0, Iter1> Iterator for InterleaveIter<Iter0, Iter1>
+where
+    Iter0: FusedIterator,
+    Iter1: Iterator<Item = Iter0::Item> + FusedIterator,
+{
+    type Item = Iter0::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = if self.next_from_1 {
+            self.iter1.next()
+        } else {
+            self.iter0.next()
+        }?;
+        self.next_from_1 = !self.next_from_1;
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower0, upper0) = self.iter0.size_hint();
+        let (lower1, upper1) = self.iter1.size_hint();
+        (lower0 + lower1, upper0.zip(upper1).map(|(a, b)| a + b))
+    }
+}
+
+impl<Iter0, Iter1> DoubleEndedIterator for InterleaveIter<Iter0, Iter1>
+where
+    Iter0: FusedIterator + DoubleEndedIterator,
+    Iter1: Iterator<Item = Iter0::Item> + FusedIterator + DoubleEndedIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let value = if self.next_back_from_0 {
+            self.iter0.next_back()
+        } else {
+            self.iter1.next_back()
+        }?;
+        self.next_back_from_0 = !self.next_back_from_0;
+        Some(value)
+    }
+}
+
+// The upper bound potentially overflows. However, since `InterleaveIter` has no
+// public constructor and the constructor of `Interleave` verifies that the
+// length does not exceed `usize::MAX`, we can safely implement
+// `ExactSizeIterator`.
+impl<Iter0, Iter1> ExactSizeIterator for InterleaveIter<Iter0, Iter1>
+where
+    Iter0: FusedIterator + ExactSizeIterator,
+    Iter1: Iterator<Item = Iter0::Item> + FusedIterator + ExactSizeIterator,
+{
+}
+
+impl<Iter0, Iter1> FusedIterator for InterleaveIter<Iter0, Iter1>
+where
+    Iter0: FusedIterator,
+    Iter1: Iterator<Item = Iter0::Item> + FusedIterator,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Interleave;
+    use crate::traits::*;
+
+    #[test]
+    fn new() {
+        assert!(Interleave::new(0..3, 3..6).is_some());
+        assert!(Interleave::new(0..3, 3..5).is_none());
+        assert!(Interleave::new(0..usize::MAX, 0..usize::MAX).is_none());
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(Interleave::new(0..3, 3..6).unwrap().len(), 6);
+        assert_eq!(Interleave::new(0..0, 3..3).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(!Interleave::new(0..3, 3..6).unwrap().is_empty());
+        assert!(Interleave::new(0..0, 3..3).unwrap().is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let x = Interleave::new(0..3, 3..6).unwrap();
+        assert_eq!(x.get(0), Some(0));
+        assert_eq!(x.get(1), Some(3));
+        assert_eq!(x.get(2), Some(1));
+        assert_eq!(x.get(5), Some(5));
+        assert_eq!(x.get(6), None);
+        assert_eq!(x.get(7), None);
+    }
+
+    #[test]
+    fn rget() {
+        let x = Interleave::new(0..3, 3..6).unwrap();
+        assert_eq!(x.rget(0), Some(5));
+        assert_eq!(x.rget(1), Some(2));
+        assert_eq!(x.rget(2), Some(4));
+        assert_eq!(x.rget(5), Some(0));
+        assert_eq!(x.rget(6), None);
+        assert_eq!(x.rget(7), None);
+    }
+
+    #[test]
+    fn first() {
+        assert_eq!(Interleave::new(0..3, 3..6).unwrap().first(), Some(0));
+        assert_eq!(Interleave::new(0..0, 3..3).unwrap().first(), None);
+    }
+
+    #[test]
+    fn last() {
+        assert_eq!(Interleave::new(0..3, 3..6).unwrap().last(), Some(5));
+        assert_eq!(Interleave::new(0..0, 3..3).unwrap().last(), None);
+    }
+
+    #[test]
+    fn iter() {
+        let x = Interleave::new(0..3, 3..6).unwrap();
+        assert!(x.iter().eq([0, 3, 1, 4, 2, 5]));
+        assert!(x.iter().rev().eq([5, 2, 4, 1, 3, 0]));
+    }
+
+    #[test]
+    fn iter_mixed() {
+        let x = Interleave::new(0..2, 2..4).unwrap();
+        let mut iter = x.iter();
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next_back(), Some(1));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn iter_size_hint() {
+        let x = Interleave::new(0..3, 3..6).unwrap();
+        let mut iter = x.iter();
+        assert_eq!(iter.size_hint(), (6, Some(6)));
+        iter.next();
+        assert_eq!(iter.size_hint(), (5, Some(5)));
+    }
+
+    #[test]
+    fn min() {
+        assert_eq!(Interleave::new(2..4, 0..2).unwrap().min(), Some(0));
+        assert_eq!(Interleave::new(0..0, 0..0).unwrap().min(), None);
+    }
+
+    #[test]
+    fn max() {
+        assert_eq!(Interleave::new(2..4, 0..2).unwrap().max(), Some(3));
+        assert_eq!(Interleave::new(0..0, 0..0).unwrap().max(), None);
+    }
+
+    #[test]
+    fn get_mut() {
+        let mut x = [0, 1];
+        let mut y = [2, 3];
+        let mut z = Interleave::new(x.as_mut_sqnc(), y.as_mut_sqnc()).unwrap();
+        *z.get_mut(1).unwrap() = 4;
+        *z.get_mut(2).unwrap() = 5;
+        assert!(z.get_mut(4).is_none());
+        assert_eq!(x, [0, 5]);
+        assert_eq!(y, [4, 3]);
+    }
+
+    #[test]
+    fn rget_mut() {
+        let mut x = [0, 1];
+        let mut y = [2, 3];
+        let mut z = Interleave::new(x.as_mut_sqnc(), y.as_mut_sqnc()).unwrap();
+        *z.rget_mut(0).unwrap() = 4;
+        *z.rget_mut(1).unwrap() = 5;
+        assert!(z.rget_mut(4).is_none());
+        assert_eq!(x, [0, 5]);
+        assert_eq!(y, [2, 4]);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut x = [0, 1];
+        let mut y = [2, 3];
+        let mut z = Interleave::new(x.as_mut_sqnc(), y.as_mut_sqnc()).unwrap();
+        z.iter_mut().zip(10..).for_each(|(v, i)| *v = i);
+        assert_eq!(x, [10, 12]);
+        assert_eq!(y, [11, 13]);
+    }
+
+    #[test]
+    fn select_iter_mut() {
+        let mut x = [0, 1];
+        let mut y = [2, 3];
+        Interleave::new(x.as_mut_sqnc(), y.as_mut_sqnc())
+            .unwrap()
+            .select(1..3)
+            .unwrap()
+            .iter_mut()
+            .for_each(|v| *v += 10);
+        assert_eq!(x, [0, 11]);
+        assert_eq!(y, [12, 3]);
+    }
+
+    #[test]
+    fn into_iter() {
+        let x = Interleave::new(0..3, 3..6).unwrap();
+        assert!(x.into_iter().eq([0, 3, 1, 4, 2, 5]));
+    }
+}
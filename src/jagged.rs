@@ -0,0 +1,376 @@
+use crate::derive::Iter;
+use crate::traits::*;
+use crate::{Slice, SliceMut, Wrapper};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ops::Range;
+
+/// A sequence of variable-length rows stored as offsets and flat values.
+///
+/// Row `i` is the contiguous subsequence of the values from offset `i` to
+/// offset `i + 1`, like the compressed rows of a CSR matrix.
+///
+/// This struct is created by [`jagged()`]. See its documentation for more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Jagged<Offsets, Values> {
+    offsets: Offsets,
+    values: Values,
+}
+
+impl<Offsets, Values> Jagged<Offsets, Values>
+where
+    Offsets: Sequence + for<'a> SequenceTypes<'a, Item = usize>,
+    Values: Sequence,
+{
+    pub(crate) fn new(offsets: Offsets, values: Values) -> Option<Self> {
+        let mut iter = offsets.iter();
+        let mut prev = iter.next()?;
+        for offset in iter {
+            if offset < prev {
+                return None;
+            }
+            prev = offset;
+        }
+        (prev <= values.len()).then_some(Self { offsets, values })
+    }
+
+    /// Returns the range of indices of row `index` or `None` if out of bounds.
+    #[inline]
+    fn row_range(&self, index: usize) -> Option<Range<usize>> {
+        Some(self.offsets.get(index)?..self.offsets.get(index.checked_add(1)?)?)
+    }
+}
+
+/// Returns a sequence of rows given by offsets into a sequence of values.
+///
+/// Row `i` of the returned sequence is a [`Slice`] of `values` from
+/// `offsets[i]` to `offsets[i + 1]`, hence the number of rows is one less than
+/// the length of `offsets`. Returns `None` if `offsets` is empty, is not
+/// monotonically increasing or if the last offset exceeds the length of
+/// `values`.
+///
+/// # Examples
+///
+/// ```
+/// use sqnc::{Sequence, MutSequence};
+///
+/// let offsets = [0, 2, 2, 5];
+/// let values = *b"abcde";
+/// let x = sqnc::jagged(offsets.copied(), values).unwrap();
+/// assert_eq!(x.len(), 3);
+/// assert!(x.get(0).unwrap().iter().eq(b"ab"));
+/// assert!(x.get(1).unwrap().is_empty());
+/// assert!(x.get(2).unwrap().iter().eq(b"cde"));
+///
+/// assert!(sqnc::jagged([0, 2, 1].copied(), values).is_none());
+/// assert!(sqnc::jagged([0, 6].copied(), values).is_none());
+/// ```
+///
+/// If the values implement [`UniqueMutSequence`], the rows can be mutated:
+///
+/// ```
+/// use sqnc::{Sequence, MutSequence};
+///
+/// let mut values = [1, 2, 3, 4, 5];
+/// let mut x = sqnc::jagged([0, 2, 5].copied(), values.as_mut_sqnc()).unwrap();
+/// for (i, mut row) in x.iter_mut().enumerate() {
+///     row.iter_mut().for_each(|v| *v *= 10_i32.pow(i as u32));
+/// }
+/// assert_eq!(values, [1, 2, 30, 40, 50]);
+/// ```
+#[inline]
+pub fn jagged<Offsets, Values>(offsets: Offsets, values: Values) -> Option<Jagged<Offsets, Values>>
+where
+    Offsets: Sequence + for<'a> SequenceTypes<'a, Item = usize>,
+    Values: Sequence,
+{
+    Jagged::new(offsets, values)
+}
+
+impl<'this, Offsets, Values> SequenceTypes<'this> for Jagged<Offsets, Values>
+where
+    Offsets: Sequence + for<'a> SequenceTypes<'a, Item = usize>,
+    Values: Sequence,
+{
+    type Item = Slice<Wrapper<&'this Values, ((),)>>;
+    type Iter = Iter<'this, Self>;
+}
+
+impl<'this, Offsets, Values> MutSequenceTypes<'this> for Jagged<Offsets, Values>
+where
+    Offsets: Sequence + for<'a> SequenceTypes<'a, Item = usize>,
+    Values: UniqueMutSequence,
+{
+    type MutItem = SliceMut<'this, Values>;
+    type IterMut = JaggedIterMut<'this, Offsets, Values>;
+}
+
+impl<Offsets, Values> Sequence for Jagged<Offsets, Values>
+where
+    Offsets: Sequence + for<'a> SequenceTypes<'a, Item = usize>,
+    Values: Sequence,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        // `offsets` contains at least one element.
+        self.offsets.len() - 1
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<<Self as SequenceTypes<'_>>::Item> {
+        Slice::new(self.values.as_sqnc(), self.row_range(index)?)
+    }
+
+    #[inline]
+    fn iter(&self) -> Iter<'_, Self> {
+        self.into()
+    }
+}
+
+impl<Offsets, Values> MutSequence for Jagged<Offsets, Values>
+where
+    Offsets: Sequence + for<'a> SequenceTypes<'a, Item = usize>,
+    Values: UniqueMutSequence,
+{
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<SliceMut<'_, Values>> {
+        // The offsets are verified upon construction, but we don't rely on
+        // `Offsets` returning the same offsets again.
+        let range = self
+            .row_range(index)
+            .filter(|range| range.start <= range.end && range.end <= self.values.len())?;
+        let values: *mut Values = &mut self.values;
+        // SAFETY: `self` is borrowed mutably for the lifetime of the row and
+        // the range is in bounds.
+        unsafe { Some(SliceMut::new(values, range)) }
+    }
+
+    #[inline]
+    fn iter_mut(&mut self) -> JaggedIterMut<'_, Offsets, Values> {
+        JaggedIterMut {
+            index: 0..self.len(),
+            bounds: 0..self.values.len(),
+            offsets: &self.offsets,
+            values: &mut self.values,
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// An iterator over the mutable rows of a [`Jagged`] sequence.
+///
+/// This struct is created by [`MutSequence::iter_mut()`] for [`Jagged`].
+pub struct JaggedIterMut<'seq, Offsets, Values> {
+    offsets: &'seq Offsets,
+    values: *mut Values,
+    index: Range<usize>,
+    // The range of values that is not covered by rows returned previously.
+    bounds: Range<usize>,
+    phantom: PhantomData<&'seq mut Values>,
+}
+
+impl<'seq, Offsets, Values> JaggedIterMut<'seq, Offsets, Values>
+where
+    Offsets: Sequence + for<'a> SequenceTypes<'a, Item = usize>,
+    Values: UniqueMutSequence,
+{
+    /// Returns the range of values of row `index`, clamped to `self.bounds`.
+    ///
+    /// The offsets are verified upon construction of [`Jagged`], in which
+    /// case clamping has no effect. Clamping guarantees that the rows returned
+    /// by this iterator are disjoint, even if `Offsets` returns inconsistent
+    /// offsets.
+    #[inline]
+    fn row_range(&self, index: usize) -> Range<usize> {
+        let Range {
+            start: lower,
+            end: upper,
+        } = self.bounds;
+        let start = self
+            .offsets
+            .get(index)
+            .map_or(lower, |v| v.clamp(lower, upper));
+        let stop = self
+            .offsets
+            .get(index + 1)
+            .map_or(start, |v| v.clamp(start, upper));
+        start..stop
+    }
+
+    #[inline]
+    fn row(&self, range: Range<usize>) -> SliceMut<'seq, Values> {
+        // SAFETY: The values are borrowed mutably for `'seq` by this
+        // iterator. The range is within `self.bounds`, which is subsequently
+        // shrunk to exclude the range, hence the rows returned by this
+        // iterator are disjoint.
+        unsafe { SliceMut::new(self.values, range) }
+    }
+}
+
+impl<'seq, Offsets, Values> Iterator for JaggedIterMut<'seq, Offsets, Values>
+where
+    Offsets: Sequence + for<'a> SequenceTypes<'a, Item = usize>,
+    Values: UniqueMutSequence,
+{
+    type Item = SliceMut<'seq, Values>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.index.next()?;
+        let range = self.row_range(index);
+        self.bounds.start = range.end;
+        Some(self.row(range))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.index.size_hint()
+    }
+}
+
+impl<'seq, Offsets, Values> DoubleEndedIterator for JaggedIterMut<'seq, Offsets, Values>
+where
+    Offsets: Sequence + for<'a> SequenceTypes<'a, Item = usize>,
+    Values: UniqueMutSequence,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.index.next_back()?;
+        let range = self.row_range(index);
+        self.bounds.end = range.start;
+        Some(self.row(range))
+    }
+}
+
+impl<'seq, Offsets, Values> ExactSizeIterator for JaggedIterMut<'seq, Offsets, Values>
+where
+    Offsets: Sequence + for<'a> SequenceTypes<'a, Item = usize>,
+    Values: UniqueMutSequence,
+{
+}
+
+impl<'seq, Offsets, Values> FusedIterator for JaggedIterMut<'seq, Offsets, Values>
+where
+    Offsets: Sequence + for<'a> SequenceTypes<'a, Item = usize>,
+    Values: UniqueMutSequence,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{jagged, Jagged};
+    use crate::traits::*;
+
+    #[test]
+    fn new() {
+        assert!(Jagged::new([0, 2, 2, 5].copied(), 0..5).is_some());
+        assert!(Jagged::new([1, 3].copied(), 0..5).is_some());
+        assert!(Jagged::new([0].copied(), 0..0).is_some());
+        assert!(Jagged::new([0; 0].copied(), 0..5).is_none());
+        assert!(Jagged::new([0, 3, 2].copied(), 0..5).is_none());
+        assert!(Jagged::new([0, 3, 6].copied(), 0..5).is_none());
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(jagged([0, 2, 2, 5].copied(), 0..5).unwrap().len(), 3);
+        assert_eq!(jagged([0].copied(), 0..5).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(!jagged([0, 2].copied(), 0..5).unwrap().is_empty());
+        assert!(jagged([0].copied(), 0..5).unwrap().is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let x = jagged([1, 3, 3, 5].copied(), 0..6).unwrap();
+        assert!(x.get(0).unwrap().iter().eq(1..3));
+        assert!(x.get(1).unwrap().iter().eq(3..3));
+        assert!(x.get(2).unwrap().iter().eq(3..5));
+        assert!(x.get(3).is_none());
+        assert!(x.get(usize::MAX).is_none());
+    }
+
+    #[test]
+    fn rget() {
+        let x = jagged([1, 3, 3, 5].copied(), 0..6).unwrap();
+        assert!(x.rget(0).unwrap().iter().eq(3..5));
+        assert!(x.rget(2).unwrap().iter().eq(1..3));
+        assert!(x.rget(3).is_none());
+    }
+
+    #[test]
+    fn iter() {
+        let x = jagged([0, 2, 5].copied(), *b"abcde").unwrap();
+        let mut iter = x.iter();
+        assert!(iter.next().unwrap().iter().eq(b"ab"));
+        assert!(iter.next().unwrap().iter().eq(b"cde"));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn map() {
+        let x = jagged([0, 2, 5].copied(), 1..6).unwrap();
+        let sums = Sequence::map(x, |row| row.iter().sum::<usize>());
+        assert!(sums.iter().eq([3, 12]));
+    }
+
+    #[test]
+    fn get_mut() {
+        let mut values = [1, 2, 3, 4, 5];
+        let mut x = jagged([0, 2, 5].copied(), values.as_mut_sqnc()).unwrap();
+        x.get_mut(1).unwrap().iter_mut().for_each(|v| *v = 0);
+        assert!(x.get_mut(2).is_none());
+        assert_eq!(values, [1, 2, 0, 0, 0]);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut values = [1, 2, 3, 4, 5];
+        let mut x = jagged([0, 2, 2, 5].copied(), values.as_mut_sqnc()).unwrap();
+        let mut iter = x.iter_mut();
+        let mut a = iter.next().unwrap();
+        let mut c = iter.next_back().unwrap();
+        let b = iter.next().unwrap();
+        assert!(iter.next().is_none());
+        assert!(b.is_empty());
+        *c.first_mut().unwrap() += *a.last().unwrap();
+        *a.first_mut().unwrap() = 0;
+        assert_eq!(values, [0, 2, 5, 4, 5]);
+    }
+
+    #[test]
+    fn iter_mut_size_hint() {
+        let mut values = [1, 2, 3, 4, 5];
+        let mut x = jagged([0, 2, 2, 5].copied(), values.as_mut_sqnc()).unwrap();
+        let mut iter = x.iter_mut();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        iter.next();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+    }
+
+    #[test]
+    fn iter_mut_inconsistent_offsets() {
+        use core::cell::Cell;
+        // Offsets that are valid upon construction, but overlap afterwards.
+        let calls = Cell::new(0);
+        let offsets = crate::from_fn(3, |i| {
+            calls.set(calls.get() + 1);
+            if calls.get() > 3 {
+                [0, 4, 1][i]
+            } else {
+                [0, 2, 5][i]
+            }
+        });
+        let mut values = [1, 2, 3, 4, 5];
+        let mut x = jagged(offsets, values.as_mut_sqnc()).unwrap();
+        let mut iter = x.iter_mut();
+        let a = iter.next().unwrap();
+        let b = iter.next().unwrap();
+        assert!(iter.next().is_none());
+        assert!(a.iter().eq(&[1, 2, 3, 4]));
+        assert!(b.is_empty());
+    }
+}
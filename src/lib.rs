@@ -12,6 +12,7 @@
 //! *   [`slice`]
 //! *   [`array`]
 //! *   [`std::ops::Range<usize>`][`std::ops::Range`]
+//! *   [`std::ops::RangeInclusive<usize>`][`std::ops::RangeInclusive`]
 //! *   [`std::collections::VecDeque`] (requires feature `alloc`),
 //! *   [`ndarray::Array1`] (requires feature `ndarray`),
 //!
@@ -187,6 +188,7 @@
 //! [GAT to be stable in Rust 1.65: implied static requirement]: https://web.archive.org/web/20221030153327/https://blog.rust-lang.org/2022/10/28/gats-stabilization.html#implied-static-requirement-from-higher-ranked-trait-bounds
 //! [The Better Alternative to Lifetime GATs]: https://web.archive.org/web/20221022065950/https://sabrinajewson.org/blog/the-better-alternative-to-lifetime-gats
 //! [`Vec`]: `std::vec::Vec`
+//! [`slice`]: prim@slice
 
 #![no_std]
 
@@ -197,26 +199,90 @@ extern crate std;
 
 // Modules.
 
+mod arange;
+mod chunks;
+#[cfg(feature = "alloc")]
+mod compress;
 mod concat;
+mod constant;
 mod copied;
 pub mod derive;
+mod element_wise;
+mod empty;
+mod enumerate;
+#[cfg(feature = "alloc")]
+mod flatten;
+mod float;
+mod format;
+mod from_fn;
+#[cfg(feature = "std")]
+mod geomspace;
+mod integer;
+mod interleave;
+mod jagged;
+mod linspace;
 mod map;
+mod multizip;
+pub mod num;
+#[cfg(feature = "rayon")]
+mod par_iter;
+#[cfg(feature = "alloc")]
+mod permutation;
+mod product;
 mod repeat;
 mod rev;
 mod select;
+#[cfg(feature = "serde")]
+mod serialize_seq;
+mod slice;
+mod sort;
+mod step_by;
 pub mod traits;
+#[cfg(feature = "alloc")]
+mod unique;
+mod windows;
 mod wrapper;
 mod zip;
 
 // Aliases.
 
+pub use arange::Arange;
+pub use chunks::{Chunks, ChunksMut};
+#[cfg(feature = "alloc")]
+pub use compress::MaskIndices;
 pub use concat::Concat;
+pub use constant::{constant, Constant};
 pub use copied::{Cloned, Copied};
+pub use element_wise::ElementWise;
+pub use empty::{empty, Empty};
+pub use enumerate::Enumerate;
+#[cfg(feature = "alloc")]
+pub use flatten::Flatten;
+pub use format::{DebugList, DisplayList};
+pub use from_fn::{from_fn, FromFn};
+#[cfg(feature = "std")]
+pub use geomspace::Geomspace;
+pub use interleave::Interleave;
+pub use jagged::{jagged, Jagged};
+pub use linspace::Linspace;
 pub use map::Map;
+pub use multizip::{multizip, MultiZip};
+#[cfg(feature = "rayon")]
+pub use par_iter::{par_iter, par_iter_mut, ParIter, ParIterMut};
+#[cfg(feature = "alloc")]
+pub use permutation::Permutation;
+pub use product::{multiproduct, MultiProduct, Product};
 pub use repeat::Repeat;
 pub use rev::Rev;
 pub use select::Select;
+#[cfg(feature = "serde")]
+pub use serialize_seq::SerializeSeq;
+pub use slice::{Slice, SliceMut};
+pub use step_by::StepBy;
 pub use traits::*;
+#[cfg(feature = "alloc")]
+pub use unique::Unique;
+pub use windows::Windows;
 pub use wrapper::{wrap, Wrapper};
 pub use zip::Zip;
 
@@ -0,0 +1,222 @@
+use crate::derive::Iter;
+use crate::float::Float;
+use crate::traits::*;
+
+/// A sequence of evenly spaced floating point numbers.
+///
+/// The first and last element are exactly `start` and `stop`, respectively.
+/// The elements in between are interpolated as `start * (1 - t) + stop * t`,
+/// with `t` the index divided by the length minus one, which does not
+/// overflow for any finite bounds.
+///
+/// # Examples
+///
+/// ```
+/// use sqnc::{Linspace, Sequence};
+///
+/// let x = Linspace::new(1.0, 2.0, 5).unwrap();
+/// assert!(x.iter().eq([1.0, 1.25, 1.5, 1.75, 2.0]));
+/// assert_eq!(x.get(1), Some(1.25));
+/// assert_eq!(x.last(), Some(2.0));
+///
+/// // The bounds must be finite.
+/// assert!(Linspace::new(0.0, f64::INFINITY, 5).is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Linspace<T> {
+    start: T,
+    stop: T,
+    len: usize,
+}
+
+impl<T> Linspace<T>
+where
+    T: Float,
+{
+    /// Returns a sequence of `len` evenly spaced numbers from `start` to
+    /// `stop`, inclusive.
+    ///
+    /// Returns `None` if `start` or `stop` is not finite. If `len` is one,
+    /// the sequence consists of `start` only.
+    #[inline]
+    pub fn new(start: T, stop: T, len: usize) -> Option<Self> {
+        if start.is_finite() && stop.is_finite() {
+            Some(Self { start, stop, len })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the distance between two consecutive elements.
+    ///
+    /// Returns zero if the sequence has less than two elements.
+    #[inline]
+    pub fn step(&self) -> T {
+        if self.len > 1 {
+            let n = T::from_usize(self.len - 1);
+            self.stop / n - self.start / n
+        } else {
+            T::zero()
+        }
+    }
+}
+
+impl<'this, T> SequenceTypes<'this> for Linspace<T>
+where
+    T: Float,
+{
+    type Item = T;
+    type Iter = Iter<'this, Self>;
+}
+
+impl<T> Sequence for Linspace<T>
+where
+    T: Float,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<T> {
+        if index >= self.len {
+            None
+        } else if index == 0 {
+            Some(self.start)
+        } else if index == self.len - 1 {
+            Some(self.stop)
+        } else {
+            let t = T::from_usize(index) / T::from_usize(self.len - 1);
+            Some(self.start * (T::from_usize(1) - t) + self.stop * t)
+        }
+    }
+
+    #[inline]
+    fn iter(&self) -> Iter<'_, Self> {
+        self.into()
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "Linspace")]
+struct LinspaceFields<T> {
+    start: T,
+    stop: T,
+    len: usize,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Linspace<T>
+where
+    T: Float + serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let LinspaceFields { start, stop, len } = LinspaceFields::deserialize(deserializer)?;
+        Self::new(start, stop, len)
+            .ok_or_else(|| serde::de::Error::custom("the bounds are not finite"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Linspace;
+    use crate::traits::*;
+
+    #[test]
+    fn new() {
+        assert!(Linspace::new(1.0, 2.0, 3).is_some());
+        assert!(Linspace::new(2.0, -1.0, 0).is_some());
+        assert!(Linspace::new(f64::NAN, 2.0, 3).is_none());
+        assert!(Linspace::new(1.0, f64::NEG_INFINITY, 3).is_none());
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(Linspace::new(0.0, 1.0, 3).unwrap().len(), 3);
+        assert_eq!(Linspace::new(0.0, 1.0, 0).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn step() {
+        assert_eq!(Linspace::new(1.0, 2.0, 5).unwrap().step(), 0.25);
+        assert_eq!(Linspace::new(2.0, 1.0, 5).unwrap().step(), -0.25);
+        assert_eq!(Linspace::new(1.0, 2.0, 1).unwrap().step(), 0.0);
+    }
+
+    #[test]
+    fn get() {
+        let x = Linspace::new(1.0f32, 2.0, 5).unwrap();
+        assert_eq!(x.get(0), Some(1.0));
+        assert_eq!(x.get(1), Some(1.25));
+        assert_eq!(x.get(4), Some(2.0));
+        assert_eq!(x.get(5), None);
+        assert_eq!(x.get(usize::MAX), None);
+        let y = Linspace::new(1.0f64, 2.0, 1).unwrap();
+        assert_eq!(y.get(0), Some(1.0));
+        assert_eq!(y.get(1), None);
+        let z = Linspace::new(1.0f64, 2.0, 0).unwrap();
+        assert_eq!(z.get(0), None);
+    }
+
+    #[test]
+    fn no_overflow() {
+        let x = Linspace::new(-f64::MAX, f64::MAX, 3).unwrap();
+        assert_eq!(x.step(), f64::MAX);
+        assert!(x.iter().eq([-f64::MAX, 0.0, f64::MAX]));
+        let y = Linspace::new(f32::MAX, -f32::MAX, 5).unwrap();
+        assert!(y.iter().all(f32::is_finite));
+        assert_eq!(y.get(2), Some(0.0));
+    }
+
+    #[test]
+    fn rget() {
+        let x = Linspace::new(1.0, 2.0, 5).unwrap();
+        assert_eq!(x.rget(0), Some(2.0));
+        assert_eq!(x.rget(1), Some(1.75));
+        assert_eq!(x.rget(4), Some(1.0));
+        assert_eq!(x.rget(5), None);
+    }
+
+    #[test]
+    fn exact_endpoints() {
+        let x = Linspace::new(0.1, 0.7, 7).unwrap();
+        assert_eq!(x.first(), Some(0.1));
+        assert_eq!(x.last(), Some(0.7));
+    }
+
+    #[test]
+    fn iter() {
+        let x = Linspace::new(0.0, -1.0, 3).unwrap();
+        assert!(x.iter().eq([0.0, -0.5, -1.0]));
+        assert!(x.iter().rev().eq([-1.0, -0.5, 0.0]));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+        let tokens = |stop| {
+            [
+                Token::Struct {
+                    name: "Linspace",
+                    len: 3,
+                },
+                Token::Str("start"),
+                Token::F64(1.0),
+                Token::Str("stop"),
+                Token::F64(stop),
+                Token::Str("len"),
+                Token::U64(3),
+                Token::StructEnd,
+            ]
+        };
+        assert_tokens(&Linspace::new(1.0, 2.0, 3).unwrap(), &tokens(2.0));
+        assert_de_tokens_error::<Linspace<f64>>(&tokens(f64::NAN), "the bounds are not finite");
+    }
+}
@@ -0,0 +1,388 @@
+use crate::traits::*;
+use core::iter::FusedIterator;
+
+/// A sequence that zips a tuple of sequences elementwise.
+///
+/// This struct is created by [`multizip()`]. See its documentation for more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MultiZip<Seqs>(Seqs);
+
+impl<Seqs> MultiZip<Seqs>
+where
+    Seqs: SequenceTuple,
+{
+    pub(crate) fn new(seqs: Seqs) -> Option<Self> {
+        seqs.has_equal_lengths().then_some(Self(seqs))
+    }
+}
+
+/// An iterator that zips a tuple of iterators elementwise.
+#[derive(Debug, Clone)]
+pub struct MultiZipIter<Iters>(Iters);
+
+/// 'Zips up' a tuple of sequences into a single sequence of tuples.
+///
+/// This is the n-ary equivalent of [`Sequence::zip()`] for up to twelve
+/// sequences. Contrary to nesting [`Sequence::zip()`], the elements of the
+/// returned sequence are flat tuples. Returns `None` if the sequences have
+/// different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use sqnc::{Sequence, MutSequence};
+///
+/// let x = 0..3;
+/// let y = [3, 4, 5];
+/// let z = *b"abc";
+/// let w = sqnc::multizip((x, y.as_sqnc(), z)).unwrap();
+/// assert_eq!(w.get(1), Some((1, &4, &b'b')));
+///
+/// assert!(sqnc::multizip((0..3, 0..4)).is_none());
+/// ```
+///
+/// A mutation to the zipped sequence propagates to the original sequences:
+///
+/// ```
+/// use sqnc::{Sequence, MutSequence};
+///
+/// let mut a = [1, 2, 3];
+/// let mut b = [4, 5, 6];
+/// let mut c = [0; 3];
+/// let mut d = sqnc::multizip((a.as_mut_sqnc(), b.as_mut_sqnc(), c.as_mut_sqnc())).unwrap();
+/// d.iter_mut().for_each(|(a, b, c)| {
+///     *c = *a * *b;
+///     *a = 0;
+/// });
+/// assert_eq!(a, [0, 0, 0]);
+/// assert_eq!(c, [4, 10, 18]);
+/// ```
+#[inline]
+pub fn multizip<Seqs>(seqs: Seqs) -> Option<MultiZip<Seqs>>
+where
+    Seqs: SequenceTuple,
+{
+    MultiZip::new(seqs)
+}
+
+macro_rules! impl_multizip {
+    ($($seq:ident $idx:tt),+) => {
+        impl<'this, $($seq),+> SequenceTypes<'this> for MultiZip<($($seq,)+)>
+        where
+            $($seq: SequenceTypes<'this>,)+
+        {
+            type Item = ($($seq::Item,)+);
+            type Iter = MultiZipIter<($($seq::Iter,)+)>;
+        }
+
+        impl<'this, $($seq),+> MutSequenceTypes<'this> for MultiZip<($($seq,)+)>
+        where
+            $($seq: MutSequenceTypes<'this>,)+
+        {
+            type MutItem = ($($seq::MutItem,)+);
+            type IterMut = MultiZipIter<($($seq::IterMut,)+)>;
+        }
+
+        impl<$($seq),+> Sequence for MultiZip<($($seq,)+)>
+        where
+            $($seq: Sequence,)+
+        {
+            #[inline]
+            fn len(&self) -> usize {
+                (self.0).0.len()
+            }
+
+            #[inline]
+            fn is_empty(&self) -> bool {
+                (self.0).0.is_empty()
+            }
+
+            #[inline]
+            fn get(&self, index: usize) -> Option<<Self as SequenceTypes<'_>>::Item> {
+                Some(($((self.0).$idx.get(index)?,)+))
+            }
+
+            #[inline]
+            fn rget(&self, rindex: usize) -> Option<<Self as SequenceTypes<'_>>::Item> {
+                Some(($((self.0).$idx.rget(rindex)?,)+))
+            }
+
+            #[inline]
+            fn first(&self) -> Option<<Self as SequenceTypes<'_>>::Item> {
+                Some(($((self.0).$idx.first()?,)+))
+            }
+
+            #[inline]
+            fn last(&self) -> Option<<Self as SequenceTypes<'_>>::Item> {
+                Some(($((self.0).$idx.last()?,)+))
+            }
+
+            #[inline]
+            fn iter(&self) -> <Self as SequenceTypes<'_>>::Iter {
+                MultiZipIter(($((self.0).$idx.iter(),)+))
+            }
+        }
+
+        impl<$($seq),+> MutSequence for MultiZip<($($seq,)+)>
+        where
+            $($seq: MutSequence,)+
+        {
+            #[inline]
+            fn get_mut(&mut self, index: usize) -> Option<<Self as MutSequenceTypes<'_>>::MutItem> {
+                Some(($((self.0).$idx.get_mut(index)?,)+))
+            }
+
+            #[inline]
+            fn rget_mut(&mut self, rindex: usize) -> Option<<Self as MutSequenceTypes<'_>>::MutItem> {
+                Some(($((self.0).$idx.rget_mut(rindex)?,)+))
+            }
+
+            #[inline]
+            fn first_mut(&mut self) -> Option<<Self as MutSequenceTypes<'_>>::MutItem> {
+                Some(($((self.0).$idx.first_mut()?,)+))
+            }
+
+            #[inline]
+            fn last_mut(&mut self) -> Option<<Self as MutSequenceTypes<'_>>::MutItem> {
+                Some(($((self.0).$idx.last_mut()?,)+))
+            }
+
+            #[inline]
+            fn iter_mut(&mut self) -> <Self as MutSequenceTypes<'_>>::IterMut {
+                MultiZipIter(($((self.0).$idx.iter_mut(),)+))
+            }
+        }
+
+        impl<$($seq),+> IntoIterator for MultiZip<($($seq,)+)>
+        where
+            $($seq: Sequence + IntoIterator,)+
+        {
+            type Item = ($(<$seq as IntoIterator>::Item,)+);
+            type IntoIter = MultiZipIter<($($seq::IntoIter,)+)>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                MultiZipIter(($((self.0).$idx.into_iter(),)+))
+            }
+        }
+
+        impl<$($seq),+> Iterator for MultiZipIter<($($seq,)+)>
+        where
+            $($seq: Iterator,)+
+        {
+            type Item = ($($seq::Item,)+);
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                Some(($((self.0).$idx.next()?,)+))
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.0).0.size_hint()
+            }
+        }
+
+        impl<$($seq),+> DoubleEndedIterator for MultiZipIter<($($seq,)+)>
+        where
+            $($seq: DoubleEndedIterator,)+
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                Some(($((self.0).$idx.next_back()?,)+))
+            }
+        }
+
+        impl<$($seq),+> ExactSizeIterator for MultiZipIter<($($seq,)+)>
+        where
+            $($seq: ExactSizeIterator,)+
+        {
+        }
+
+        impl<$($seq),+> FusedIterator for MultiZipIter<($($seq,)+)>
+        where
+            $($seq: FusedIterator,)+
+        {
+        }
+    };
+}
+
+impl_multizip! {S0 0}
+impl_multizip! {S0 0, S1 1}
+impl_multizip! {S0 0, S1 1, S2 2}
+impl_multizip! {S0 0, S1 1, S2 2, S3 3}
+impl_multizip! {S0 0, S1 1, S2 2, S3 3, S4 4}
+impl_multizip! {S0 0, S1 1, S2 2, S3 3, S4 4, S5 5}
+impl_multizip! {S0 0, S1 1, S2 2, S3 3, S4 4, S5 5, S6 6}
+impl_multizip! {S0 0, S1 1, S2 2, S3 3, S4 4, S5 5, S6 6, S7 7}
+impl_multizip! {S0 0, S1 1, S2 2, S3 3, S4 4, S5 5, S6 6, S7 7, S8 8}
+impl_multizip! {S0 0, S1 1, S2 2, S3 3, S4 4, S5 5, S6 6, S7 7, S8 8, S9 9}
+impl_multizip! {S0 0, S1 1, S2 2, S3 3, S4 4, S5 5, S6 6, S7 7, S8 8, S9 9, S10 10}
+impl_multizip! {S0 0, S1 1, S2 2, S3 3, S4 4, S5 5, S6 6, S7 7, S8 8, S9 9, S10 10, S11 11}
+
+#[cfg(test)]
+mod tests {
+    use super::{multizip, MultiZip};
+    use crate::traits::*;
+
+    #[test]
+    fn new() {
+        assert!(MultiZip::new((0..3,)).is_some());
+        assert!(MultiZip::new((0..3, *b"abc", [0.; 3])).is_some());
+        assert!(MultiZip::new((0..3, *b"abc", [0.; 4])).is_none());
+        assert!(MultiZip::new((0..3, *b"ab", [0.; 3])).is_none());
+    }
+
+    #[test]
+    fn len() {
+        let x = multizip((0..3, *b"abc", 3..6)).unwrap();
+        assert_eq!(x.len(), 3);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(!multizip((0..3, *b"abc")).unwrap().is_empty());
+        assert!(multizip((0..0, *b"")).unwrap().is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let x = multizip((0..3, *b"abc", 3..6)).unwrap();
+        assert_eq!(x.get(0), Some((0, &b'a', 3)));
+        assert_eq!(x.get(2), Some((2, &b'c', 5)));
+        assert_eq!(x.get(3), None);
+    }
+
+    #[test]
+    fn rget() {
+        let x = multizip((0..3, *b"abc", 3..6)).unwrap();
+        assert_eq!(x.rget(0), Some((2, &b'c', 5)));
+        assert_eq!(x.rget(2), Some((0, &b'a', 3)));
+        assert_eq!(x.rget(3), None);
+    }
+
+    #[test]
+    fn first() {
+        assert_eq!(multizip((0..3, *b"abc")).unwrap().first(), Some((0, &b'a')));
+        assert_eq!(multizip((0..0, *b"")).unwrap().first(), None);
+    }
+
+    #[test]
+    fn last() {
+        assert_eq!(multizip((0..3, *b"abc")).unwrap().last(), Some((2, &b'c')));
+        assert_eq!(multizip((0..0, *b"")).unwrap().last(), None);
+    }
+
+    #[test]
+    fn iter() {
+        let x = multizip((0..3, *b"abc", 3..6)).unwrap();
+        assert!(x.iter().eq([(0, &b'a', 3), (1, &b'b', 4), (2, &b'c', 5)]));
+    }
+
+    #[test]
+    fn rev_iter() {
+        let x = multizip((0..3, *b"abc")).unwrap();
+        assert!(x.iter().rev().eq([(2, &b'c'), (1, &b'b'), (0, &b'a')]));
+    }
+
+    #[test]
+    fn iter_size_hint() {
+        let x = multizip((0..3, *b"abc")).unwrap();
+        let mut iter = x.iter();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        iter.next();
+        assert_eq!(iter.len(), 2);
+    }
+
+    #[test]
+    fn get_mut() {
+        let mut x = [0, 1, 2];
+        let mut y = *b"abc";
+        let mut z = multizip((x.as_mut_sqnc(), y.as_mut_sqnc())).unwrap();
+        if let Some((a, b)) = z.get_mut(1) {
+            (*a, *b) = (4, b'e');
+        }
+        assert!(z.get_mut(3).is_none());
+        assert_eq!(x, [0, 4, 2]);
+        assert_eq!(y, *b"aec");
+    }
+
+    #[test]
+    fn rget_mut() {
+        let mut x = [0, 1, 2];
+        let mut y = *b"abc";
+        let mut z = multizip((x.as_mut_sqnc(), y.as_mut_sqnc())).unwrap();
+        if let Some((a, b)) = z.rget_mut(0) {
+            (*a, *b) = (5, b'f');
+        }
+        assert!(z.rget_mut(3).is_none());
+        assert_eq!(x, [0, 1, 5]);
+        assert_eq!(y, *b"abf");
+    }
+
+    #[test]
+    fn first_mut() {
+        let mut x = [0, 1, 2];
+        let mut y = *b"abc";
+        let mut z = multizip((x.as_mut_sqnc(), y.as_mut_sqnc())).unwrap();
+        if let Some((a, b)) = z.first_mut() {
+            (*a, *b) = (3, b'd');
+        }
+        assert_eq!(x, [3, 1, 2]);
+        assert_eq!(y, *b"dbc");
+    }
+
+    #[test]
+    fn last_mut() {
+        let mut x = [0, 1, 2];
+        let mut y = *b"abc";
+        let mut z = multizip((x.as_mut_sqnc(), y.as_mut_sqnc())).unwrap();
+        if let Some((a, b)) = z.last_mut() {
+            (*a, *b) = (5, b'f');
+        }
+        assert_eq!(x, [0, 1, 5]);
+        assert_eq!(y, *b"abf");
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut x = [0, 1, 2];
+        let mut y = [3, 4, 5];
+        let mut z = [0; 3];
+        multizip((x.as_mut_sqnc(), y.as_mut_sqnc(), z.as_mut_sqnc()))
+            .unwrap()
+            .iter_mut()
+            .for_each(|(a, b, c)| {
+                *c = *a + *b;
+                *a += 1;
+            });
+        assert_eq!(x, [1, 2, 3]);
+        assert_eq!(z, [3, 5, 7]);
+    }
+
+    #[test]
+    fn into_iter() {
+        let x = multizip((0..3, 3..6, 6..9)).unwrap();
+        assert!(x.into_iter().eq([(0, 3, 6), (1, 4, 7), (2, 5, 8)]));
+    }
+
+    #[test]
+    fn twelve() {
+        let x = multizip((
+            0..2,
+            1..3,
+            2..4,
+            3..5,
+            4..6,
+            5..7,
+            6..8,
+            7..9,
+            8..10,
+            9..11,
+            10..12,
+            11..13,
+        ))
+        .unwrap();
+        assert_eq!(x.get(1), Some((1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12)));
+    }
+}
@@ -0,0 +1,8 @@
+//! Numeric traits
+//!
+//! The traits in this module are sealed and implemented for the primitive
+//! number types supported by [`Arange`][`crate::Arange`],
+//! [`Linspace`][`crate::Linspace`] and [`Geomspace`][`crate::Geomspace`].
+
+pub use crate::float::Float;
+pub use crate::integer::Integer;
@@ -0,0 +1,388 @@
+use crate::derive::Iter;
+use crate::traits::*;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ops::Range;
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+/// Parallel iterator over the elements of a [`Sequence`].
+///
+/// This struct is created by [`par_iter()`]. See its documentation for more.
+pub struct ParIter<'a, S> {
+    sequence: &'a S,
+}
+
+/// Returns a parallel iterator over the elements of a sequence.
+///
+/// The sequence is split into ranges of indices which are processed in
+/// parallel by [rayon](https://docs.rs/rayon), using only
+/// [`Sequence::get()`]. The returned iterator is an
+/// [`IndexedParallelIterator`]. Requires feature `rayon`.
+///
+/// This is a free function rather than a method of [`Sequence`] to avoid
+/// ambiguity with rayon's `par_iter()` for slices, arrays and vectors.
+///
+/// # Examples
+///
+/// ```
+/// use rayon::prelude::*;
+/// use sqnc::Sequence;
+///
+/// let x = Sequence::map(0..1000, |v| (v * v) as u64);
+/// assert_eq!(sqnc::par_iter(&x).sum::<u64>(), 332_833_500);
+///
+/// let y: Vec<u64> = sqnc::par_iter(&x.select(990..1000).unwrap()).collect();
+/// assert_eq!(y[0], 980_100);
+/// ```
+#[inline]
+pub fn par_iter<S>(sequence: &S) -> ParIter<'_, S>
+where
+    S: Sequence + Sync,
+{
+    ParIter { sequence }
+}
+
+impl<'a, S> ParallelIterator for ParIter<'a, S>
+where
+    S: Sequence + Sync,
+    <S as SequenceTypes<'a>>::Item: Send,
+{
+    type Item = <S as SequenceTypes<'a>>::Item;
+
+    #[inline]
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    #[inline]
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.sequence.len())
+    }
+}
+
+impl<'a, S> IndexedParallelIterator for ParIter<'a, S>
+where
+    S: Sequence + Sync,
+    <S as SequenceTypes<'a>>::Item: Send,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.sequence.len()
+    }
+
+    #[inline]
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: Consumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    #[inline]
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: ProducerCallback<Self::Item>,
+    {
+        let index = 0..self.sequence.len();
+        callback.callback(SeqProducer {
+            sequence: self.sequence,
+            index,
+        })
+    }
+}
+
+/// Producer for [`ParIter`] that covers a range of indices.
+struct SeqProducer<'a, S> {
+    sequence: &'a S,
+    index: Range<usize>,
+}
+
+impl<'a, S> Producer for SeqProducer<'a, S>
+where
+    S: Sequence + Sync,
+    <S as SequenceTypes<'a>>::Item: Send,
+{
+    type Item = <S as SequenceTypes<'a>>::Item;
+    type IntoIter = Iter<'a, S>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, S> {
+        Iter::with_range(self.sequence, self.index)
+    }
+
+    #[inline]
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = self.index.start + index;
+        let left = Self {
+            sequence: self.sequence,
+            index: self.index.start..mid,
+        };
+        let right = Self {
+            sequence: self.sequence,
+            index: mid..self.index.end,
+        };
+        (left, right)
+    }
+}
+
+/// Parallel iterator over mutable references to the elements of a [`MutSequence`].
+///
+/// This struct is created by [`par_iter_mut()`]. See its documentation for more.
+pub struct ParIterMut<'a, S> {
+    sequence: &'a mut S,
+}
+
+/// Returns a parallel iterator over mutable references to the elements of a
+/// sequence.
+///
+/// The sequence is split into disjoint ranges of indices which are processed
+/// in parallel by [rayon](https://docs.rs/rayon), using only
+/// [`MutSequence::get_mut()`]. The sequence must be a [`UniqueMutSequence`],
+/// such that the elements returned for the disjoint ranges don't alias. The
+/// returned iterator is an [`IndexedParallelIterator`]. Requires feature
+/// `rayon`.
+///
+/// # Examples
+///
+/// ```
+/// use rayon::prelude::*;
+/// use sqnc::traits::*;
+///
+/// let mut x: Vec<usize> = (0..10).collect();
+/// let mut y = x.as_mut_sqnc().sqnc_step_by(3).unwrap();
+/// sqnc::par_iter_mut(&mut y).for_each(|v| *v = 0);
+/// assert_eq!(x, [0, 1, 2, 0, 4, 5, 0, 7, 8, 0]);
+/// ```
+#[inline]
+pub fn par_iter_mut<S>(sequence: &mut S) -> ParIterMut<'_, S>
+where
+    S: UniqueMutSequence + Send + Sync,
+{
+    ParIterMut { sequence }
+}
+
+impl<'a, S> ParallelIterator for ParIterMut<'a, S>
+where
+    S: UniqueMutSequence + Send + Sync,
+    <S as MutSequenceTypes<'a>>::MutItem: Send,
+{
+    type Item = <S as MutSequenceTypes<'a>>::MutItem;
+
+    #[inline]
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    #[inline]
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.sequence.len())
+    }
+}
+
+impl<'a, S> IndexedParallelIterator for ParIterMut<'a, S>
+where
+    S: UniqueMutSequence + Send + Sync,
+    <S as MutSequenceTypes<'a>>::MutItem: Send,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.sequence.len()
+    }
+
+    #[inline]
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: Consumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    #[inline]
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: ProducerCallback<Self::Item>,
+    {
+        let index = 0..self.sequence.len();
+        callback.callback(SeqProducerMut {
+            sequence: self.sequence,
+            index,
+            phantom: PhantomData,
+        })
+    }
+}
+
+/// Producer for [`ParIterMut`] that covers a range of indices.
+///
+/// The producers obtained by splitting share the sequence, but cover disjoint
+/// ranges of indices, like [`crate::SliceMut`] and [`crate::ChunksMut`].
+struct SeqProducerMut<'a, S> {
+    sequence: *mut S,
+    index: Range<usize>,
+    phantom: PhantomData<&'a mut S>,
+}
+
+// SAFETY: The producers only call `MutSequence::get_mut()` on the shared
+// sequence, possibly from different threads, for disjoint ranges of indices.
+// Since `S` is `Sync`, accessing the internals of `S` from different threads
+// is safe and since `S` is a `UniqueMutSequence`, `get_mut()` does not modify
+// the sequence other than via the returned element, which is `Send`.
+unsafe impl<'a, S> Send for SeqProducerMut<'a, S>
+where
+    S: UniqueMutSequence + Send + Sync,
+    <S as MutSequenceTypes<'a>>::MutItem: Send,
+{
+}
+
+impl<'a, S> Producer for SeqProducerMut<'a, S>
+where
+    S: UniqueMutSequence + Send + Sync,
+    <S as MutSequenceTypes<'a>>::MutItem: Send,
+{
+    type Item = <S as MutSequenceTypes<'a>>::MutItem;
+    type IntoIter = IterMut<'a, S>;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, S> {
+        IterMut {
+            sequence: self.sequence,
+            index: self.index,
+            phantom: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = self.index.start + index;
+        let left = Self {
+            sequence: self.sequence,
+            index: self.index.start..mid,
+            phantom: PhantomData,
+        };
+        let right = Self {
+            sequence: self.sequence,
+            index: mid..self.index.end,
+            phantom: PhantomData,
+        };
+        (left, right)
+    }
+}
+
+/// Iterator that calls [`MutSequence::get_mut()`] for a range of indices.
+struct IterMut<'a, S> {
+    sequence: *mut S,
+    index: Range<usize>,
+    phantom: PhantomData<&'a mut S>,
+}
+
+impl<'a, S> IterMut<'a, S>
+where
+    S: UniqueMutSequence,
+{
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<<S as MutSequenceTypes<'a>>::MutItem> {
+        // SAFETY: The sequence is borrowed mutably for `'a` by `ParIterMut`
+        // and every index is visited at most once by all producers combined.
+        // By the contract of `UniqueMutSequence` the returned elements are
+        // unique and remain valid while other elements are obtained.
+        let sequence: &'a mut S = unsafe { &mut *self.sequence };
+        sequence.get_mut(index)
+    }
+}
+
+impl<'a, S> Iterator for IterMut<'a, S>
+where
+    S: UniqueMutSequence,
+{
+    type Item = <S as MutSequenceTypes<'a>>::MutItem;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.index.next()?;
+        self.get_mut(index)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.index.size_hint()
+    }
+}
+
+impl<'a, S> DoubleEndedIterator for IterMut<'a, S>
+where
+    S: UniqueMutSequence,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.index.next_back()?;
+        self.get_mut(index)
+    }
+}
+
+impl<'a, S> ExactSizeIterator for IterMut<'a, S> where S: UniqueMutSequence {}
+
+impl<'a, S> FusedIterator for IterMut<'a, S> where S: UniqueMutSequence {}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::{par_iter, par_iter_mut};
+    use crate::traits::*;
+    use alloc::vec::Vec;
+    use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+    #[test]
+    fn par_iter_select() {
+        let x: Vec<usize> = (0..1000).collect();
+        let y = Sequence::map(x.as_sqnc().select(Sequence::rev(0..1000)).unwrap(), |v| {
+            v * 2
+        });
+        let z: Vec<usize> = par_iter(&y).collect();
+        assert!(z.iter().copied().eq(Iterator::rev(0..1000).map(|v| v * 2)));
+        assert_eq!(par_iter(&y).sum::<usize>(), 999 * 1000);
+        assert_eq!(par_iter(&y).len(), 1000);
+    }
+
+    #[test]
+    fn par_iter_concat_zip() {
+        let x = [1, 2, 3].copied().concat(4..101).unwrap();
+        let y = Sequence::zip(x, 0..100).unwrap();
+        assert_eq!(par_iter(&y).map(|(a, b)| a - b).sum::<usize>(), 100);
+        assert_eq!(par_iter(&y).position_first(|(a, _)| a == 50), Some(49));
+    }
+
+    #[test]
+    fn par_iter_empty() {
+        assert_eq!(par_iter(&crate::empty::<usize>()).count(), 0);
+    }
+
+    #[test]
+    fn par_iter_mut_select_rev() {
+        let mut x: Vec<usize> = (0..1000).collect();
+        let mut y = x
+            .as_mut_sqnc()
+            .select((0..1000).sqnc_step_by(2).unwrap())
+            .unwrap();
+        par_iter_mut(&mut y).for_each(|v| *v = 0);
+        assert!(x.iter().enumerate().all(|(i, v)| *v == i % 2 * i));
+        let mut z = x.as_mut_sqnc().rev();
+        par_iter_mut(&mut z).enumerate().for_each(|(i, v)| *v += i);
+        assert!(x.iter().enumerate().all(|(i, v)| *v == i % 2 * i + 999 - i));
+        assert_eq!(par_iter_mut(&mut x.as_mut_sqnc().rev()).len(), 1000);
+    }
+
+    #[test]
+    fn rayon_par_iter() {
+        use rayon::iter::IntoParallelRefIterator;
+        let x: Vec<usize> = (0..100).collect();
+        assert_eq!(x.par_iter().sum::<usize>(), 4950);
+        assert_eq!([1, 2, 3].par_iter().sum::<usize>(), 6);
+        assert_eq!(x.as_sqnc().len(), 100);
+    }
+}
@@ -0,0 +1,333 @@
+extern crate alloc;
+use crate::derive::Iter;
+use crate::traits::*;
+use crate::{sort, wrap, Copied, Select, Wrapper};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A sequence of indices that is validated to be a permutation.
+///
+/// A permutation of length `n` contains every index in `0..n` exactly once.
+/// This is checked once, upon construction, after which [`Permutation`]
+/// implements [`UniqueSequence`]. Hence, a [`Permutation`] can be used as
+/// indices of a mutable [`Sequence::select()`], even if the underlying indices
+/// don't implement [`UniqueSequence`], e.g. a vector of indices.
+///
+/// The inverse of the permutation is stored alongside the indices. This
+/// guarantees that the elements of this sequence are unique, even if the
+/// underlying indices would change after construction, and allows inverting
+/// the permutation in *O*(*n*). Requires feature `alloc`.
+///
+/// # Examples
+///
+/// ```
+/// use sqnc::{MutSequence, Permutation, Sequence};
+///
+/// let mut x = ['a', 'b', 'c', 'd'];
+/// let p = Permutation::new([2, 0, 3, 1].copied()).unwrap();
+/// *x.as_mut_sqnc().select(p).unwrap().first_mut().unwrap() = 'e';
+/// assert_eq!(x, ['a', 'b', 'e', 'd']);
+///
+/// assert!(Permutation::new([2, 0, 2, 1].copied()).is_none());
+/// assert!(Permutation::new([2, 0, 4, 1].copied()).is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Permutation<Idx> {
+    indices: Idx,
+    inverse: Vec<usize>,
+}
+
+impl<Idx> Permutation<Idx>
+where
+    Idx: Sequence + for<'a> SequenceTypes<'a, Item = usize>,
+{
+    /// Returns a permutation or `None` if `indices` is not a permutation.
+    pub fn new(indices: Idx) -> Option<Self> {
+        let len = indices.len();
+        let mut inverse = vec![usize::MAX; len];
+        let mut count = 0;
+        for (i, index) in indices.iter().enumerate() {
+            let slot = inverse.get_mut(index)?;
+            if *slot != usize::MAX {
+                return None;
+            }
+            *slot = i;
+            count += 1;
+        }
+        (count == len).then_some(Self { indices, inverse })
+    }
+
+    /// Returns the underlying indices.
+    #[inline]
+    pub fn unwrap(self) -> Idx {
+        self.indices
+    }
+
+    /// Returns the inverse of this permutation.
+    ///
+    /// Selecting a sequence by a permutation and then by its inverse gives the
+    /// original sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::{Permutation, Sequence};
+    ///
+    /// let p = Permutation::new([2, 0, 3, 1].copied()).unwrap();
+    /// let q = p.inverse();
+    /// assert!(q.iter().eq([1, 3, 0, 2]));
+    ///
+    /// let x = *b"abcd";
+    /// let y = x.select(p).unwrap().select(q).unwrap();
+    /// assert!(y.iter().eq(b"abcd"));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn inverse(&self) -> Permutation<Copied<Wrapper<Vec<usize>, ((),)>, usize>> {
+        let mut forward = vec![0; self.inverse.len()];
+        for (k, &i) in self.inverse.iter().enumerate() {
+            forward[i] = k;
+        }
+        Permutation {
+            indices: wrap(self.inverse.clone()).copied(),
+            inverse: forward,
+        }
+    }
+
+    /// Returns the composition of this permutation with another permutation.
+    ///
+    /// Element `i` of the returned permutation is element `other[i]` of this
+    /// permutation, such that selecting a sequence by the composition is the
+    /// same as selecting by `self` and subsequently by `other`. Returns `None`
+    /// if the permutations have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::{Permutation, Sequence};
+    ///
+    /// let p = Permutation::new([2, 0, 3, 1].copied()).unwrap();
+    /// let q = Permutation::new([1, 0, 2, 3].copied()).unwrap();
+    /// let r = p.clone().compose(q.clone()).unwrap();
+    /// assert!(r.iter().eq([0, 2, 3, 1]));
+    ///
+    /// let x = *b"abcd";
+    /// let y = x.select(p).unwrap().select(q).unwrap();
+    /// let z = x.select(r).unwrap();
+    /// assert!(y.iter().eq(z.iter()));
+    /// ```
+    pub fn compose<Other>(
+        self,
+        other: Permutation<Other>,
+    ) -> Option<Permutation<Select<Idx, Permutation<Other>>>>
+    where
+        Other: Sequence + for<'a> SequenceTypes<'a, Item = usize>,
+    {
+        if self.len() != other.len() {
+            return None;
+        }
+        let inverse = self.inverse.iter().map(|&i| other.inverse[i]).collect();
+        Some(Permutation {
+            indices: self.indices.select(other)?,
+            inverse,
+        })
+    }
+
+    /// Reorders a sequence in place according to this permutation.
+    ///
+    /// After reordering, element `i` of the sequence is the element at index
+    /// `self[i]` before reordering, i.e. the sequence equals the selection of
+    /// the original sequence by this permutation. Returns `None` and reorders
+    /// nothing if the length of the sequence differs from the length of this
+    /// permutation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::{MutSequence, Permutation, Sequence};
+    ///
+    /// let p = Permutation::new([2, 0, 3, 1].copied()).unwrap();
+    /// let mut x = *b"abcd";
+    /// p.apply_in_place(&mut x).unwrap();
+    /// assert_eq!(&x, b"cadb");
+    /// ```
+    pub fn apply_in_place<S, T>(&self, seq: &mut S) -> Option<()>
+    where
+        S: UniqueMutSequence + for<'a> MutSequenceTypes<'a, MutItem = &'a mut T> + ?Sized,
+    {
+        let len = self.len();
+        if seq.len() != len {
+            return None;
+        }
+        let mut visited = vec![false; len];
+        for start in 0..len {
+            if visited[start] {
+                continue;
+            }
+            visited[start] = true;
+            let mut k = start;
+            loop {
+                let next = self.get(k)?;
+                if next == start {
+                    break;
+                }
+                visited[next] = true;
+                sort::swap(seq, k, next)?;
+                k = next;
+            }
+        }
+        Some(())
+    }
+}
+
+impl<'this, Idx> SequenceTypes<'this> for Permutation<Idx>
+where
+    Idx: Sequence + for<'a> SequenceTypes<'a, Item = usize>,
+{
+    type Item = usize;
+    type Iter = Iter<'this, Self>;
+}
+
+impl<Idx> Sequence for Permutation<Idx>
+where
+    Idx: Sequence + for<'a> SequenceTypes<'a, Item = usize>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.inverse.len()
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<usize> {
+        let value = self.indices.get(index)?;
+        // Only return `value` if it is consistent with the inverse, which
+        // guarantees uniqueness.
+        (self.inverse.get(value) == Some(&index)).then_some(value)
+    }
+
+    #[inline]
+    fn iter(&self) -> Iter<'_, Self> {
+        self.into()
+    }
+
+    #[inline]
+    fn min<'a>(&'a self) -> Option<usize>
+    where
+        <Self as SequenceTypes<'a>>::Item: Ord,
+    {
+        (!self.is_empty()).then_some(0)
+    }
+
+    #[inline]
+    fn max<'a>(&'a self) -> Option<usize>
+    where
+        <Self as SequenceTypes<'a>>::Item: Ord,
+    {
+        self.len().checked_sub(1)
+    }
+}
+
+// SAFETY: `Permutation::get()` returns `value` for `index` only if
+// `inverse[value] == index`. Since `inverse` maps every value to a single
+// index, distinct indices give distinct values.
+unsafe impl<Idx> UniqueSequence for Permutation<Idx> where
+    Idx: Sequence + for<'a> SequenceTypes<'a, Item = usize>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Permutation;
+    use crate::traits::*;
+
+    #[test]
+    fn new() {
+        assert!(Permutation::new([2, 0, 1].copied()).is_some());
+        assert!(Permutation::new(0..0).is_some());
+        assert!(Permutation::new([2, 0, 2].copied()).is_none());
+        assert!(Permutation::new([2, 0, 3].copied()).is_none());
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(Permutation::new([2, 0, 1].copied()).unwrap().len(), 3);
+        assert_eq!(Permutation::new(0..0).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn get() {
+        let p = Permutation::new([2, 0, 1].copied()).unwrap();
+        assert_eq!(p.get(0), Some(2));
+        assert_eq!(p.get(2), Some(1));
+        assert_eq!(p.get(3), None);
+    }
+
+    #[test]
+    fn iter() {
+        let p = Permutation::new([2, 0, 1].copied()).unwrap();
+        assert!(p.iter().eq([2, 0, 1]));
+        assert!(p.iter().rev().eq([1, 0, 2]));
+    }
+
+    #[test]
+    fn min_max() {
+        let p = Permutation::new([2, 0, 1].copied()).unwrap();
+        assert_eq!(p.min(), Some(0));
+        assert_eq!(p.max(), Some(2));
+        let q = Permutation::new(0..0).unwrap();
+        assert_eq!(q.min(), None);
+        assert_eq!(q.max(), None);
+    }
+
+    #[test]
+    fn unwrap() {
+        let p = Permutation::new([2, 0, 1].copied()).unwrap();
+        assert!(p.unwrap().iter().eq([2, 0, 1]));
+    }
+
+    #[test]
+    fn inverse() {
+        let p = Permutation::new([2, 0, 3, 1].copied()).unwrap();
+        let q = p.inverse();
+        assert!(q.iter().eq([1, 3, 0, 2]));
+        assert!(q.inverse().iter().eq([2, 0, 3, 1]));
+    }
+
+    #[test]
+    fn compose() {
+        let p = Permutation::new([2, 0, 3, 1].copied()).unwrap();
+        let q = Permutation::new([3, 2, 1, 0].copied()).unwrap();
+        let r = p.compose(q).unwrap();
+        assert!(r.iter().eq([1, 3, 0, 2]));
+        assert!(r.inverse().iter().eq([2, 0, 3, 1]));
+        let s = Permutation::new([1, 0].copied()).unwrap();
+        assert!(r.compose(s).is_none());
+    }
+
+    #[test]
+    fn apply_in_place() {
+        let p = Permutation::new([1, 2, 0, 4, 3, 5].copied()).unwrap();
+        let mut x = ['a', 'b', 'c', 'd', 'e', 'f'];
+        assert_eq!(p.apply_in_place(&mut x), Some(()));
+        assert_eq!(x, ['b', 'c', 'a', 'e', 'd', 'f']);
+        assert_eq!(p.apply_in_place(&mut ['a']), None);
+    }
+
+    #[test]
+    fn apply_in_place_inverse() {
+        let p = Permutation::new([3, 0, 4, 1, 2].copied()).unwrap();
+        let mut x = [0, 1, 2, 3, 4];
+        p.apply_in_place(&mut x).unwrap();
+        assert!(x.iter().copied().eq(p.iter()));
+        p.inverse().apply_in_place(&mut x).unwrap();
+        assert_eq!(x, [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn select_mut() {
+        let mut x = [0, 1, 2, 3];
+        let p = Permutation::new([3, 1, 0, 2].copied()).unwrap();
+        let mut y = x.as_mut_sqnc().select(p).unwrap();
+        y.iter_mut().zip(4..).for_each(|(v, w)| *v = w);
+        assert_eq!(x, [6, 5, 7, 4]);
+    }
+}
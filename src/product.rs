@@ -0,0 +1,284 @@
+use crate::derive::Iter;
+use crate::traits::*;
+
+/// The cartesian product of two sequences.
+///
+/// This struct is created by [`Sequence::sqnc_product()`]. See its documentation
+/// for more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Product<Seq0, Seq1>(Seq0, Seq1);
+
+impl<Seq0, Seq1> Product<Seq0, Seq1>
+where
+    Seq0: Sequence,
+    Seq1: Sequence,
+{
+    #[inline]
+    pub(crate) fn new(seq0: Seq0, seq1: Seq1) -> Option<Self> {
+        if seq0.len().checked_mul(seq1.len()).is_some() {
+            Some(Self(seq0, seq1))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'this, Seq0, Seq1> SequenceTypes<'this> for Product<Seq0, Seq1>
+where
+    Seq0: Sequence,
+    Seq1: Sequence,
+{
+    type Item = (
+        <Seq0 as SequenceTypes<'this>>::Item,
+        <Seq1 as SequenceTypes<'this>>::Item,
+    );
+    type Iter = Iter<'this, Self>;
+}
+
+impl<Seq0, Seq1> Sequence for Product<Seq0, Seq1>
+where
+    Seq0: Sequence,
+    Seq1: Sequence,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len() * self.1.len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.0.is_empty() || self.1.is_empty()
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<<Self as SequenceTypes<'_>>::Item> {
+        if index < self.len() {
+            let n = self.1.len();
+            Some((self.0.get(index / n)?, self.1.get(index % n)?))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn first(&self) -> Option<<Self as SequenceTypes<'_>>::Item> {
+        self.0.first().zip(self.1.first())
+    }
+
+    #[inline]
+    fn last(&self) -> Option<<Self as SequenceTypes<'_>>::Item> {
+        self.0.last().zip(self.1.last())
+    }
+
+    #[inline]
+    fn iter(&self) -> Iter<'_, Self> {
+        self.into()
+    }
+}
+
+/// The cartesian product of a tuple of sequences.
+///
+/// This struct is created by [`multiproduct()`]. See its documentation for
+/// more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MultiProduct<Seqs> {
+    seqs: Seqs,
+    len: usize,
+}
+
+impl<Seqs> MultiProduct<Seqs>
+where
+    Seqs: SequenceTuple,
+{
+    #[inline]
+    pub(crate) fn new(seqs: Seqs) -> Option<Self> {
+        let len = seqs.checked_product_of_lengths()?;
+        Some(Self { seqs, len })
+    }
+}
+
+/// Returns the cartesian product of a tuple of sequences.
+///
+/// This is the n-ary equivalent of [`Sequence::sqnc_product()`] for up to twelve
+/// sequences. The elements of the returned sequence are tuples, ordered such
+/// that the last sequence varies fastest. Returns `None` if the length of the
+/// product overflows.
+///
+/// # Examples
+///
+/// ```
+/// use sqnc::Sequence;
+///
+/// let x = sqnc::multiproduct((0..2, *b"ab", 5..7)).unwrap();
+/// assert_eq!(x.len(), 8);
+/// assert_eq!(x.get(0), Some((0, &b'a', 5)));
+/// assert_eq!(x.get(1), Some((0, &b'a', 6)));
+/// assert_eq!(x.get(2), Some((0, &b'b', 5)));
+/// assert_eq!(x.get(7), Some((1, &b'b', 6)));
+///
+/// assert!(sqnc::multiproduct((0..usize::MAX, 0..2, 0..1)).is_none());
+/// ```
+#[inline]
+pub fn multiproduct<Seqs>(seqs: Seqs) -> Option<MultiProduct<Seqs>>
+where
+    Seqs: SequenceTuple,
+{
+    MultiProduct::new(seqs)
+}
+
+macro_rules! impl_multiproduct {
+    ($($seq:ident $idx:tt),+) => {
+        impl<'this, $($seq),+> SequenceTypes<'this> for MultiProduct<($($seq,)+)>
+        where
+            $($seq: Sequence,)+
+        {
+            type Item = ($(<$seq as SequenceTypes<'this>>::Item,)+);
+            type Iter = Iter<'this, Self>;
+        }
+
+        impl<$($seq),+> Sequence for MultiProduct<($($seq,)+)>
+        where
+            $($seq: Sequence,)+
+        {
+            #[inline]
+            fn len(&self) -> usize {
+                self.len
+            }
+
+            #[inline]
+            fn get(&self, index: usize) -> Option<<Self as SequenceTypes<'_>>::Item> {
+                if index >= self.len {
+                    return None;
+                }
+                // Mixed-radix decomposition of `index`. Since `index` is in
+                // range, all sequences are non-empty.
+                let mut stride = self.len;
+                Some(($({
+                    let n = self.seqs.$idx.len();
+                    stride /= n;
+                    self.seqs.$idx.get(index / stride % n)?
+                },)+))
+            }
+
+            #[inline]
+            fn iter(&self) -> Iter<'_, Self> {
+                self.into()
+            }
+        }
+    };
+}
+
+impl_multiproduct! {S0 0}
+impl_multiproduct! {S0 0, S1 1}
+impl_multiproduct! {S0 0, S1 1, S2 2}
+impl_multiproduct! {S0 0, S1 1, S2 2, S3 3}
+impl_multiproduct! {S0 0, S1 1, S2 2, S3 3, S4 4}
+impl_multiproduct! {S0 0, S1 1, S2 2, S3 3, S4 4, S5 5}
+impl_multiproduct! {S0 0, S1 1, S2 2, S3 3, S4 4, S5 5, S6 6}
+impl_multiproduct! {S0 0, S1 1, S2 2, S3 3, S4 4, S5 5, S6 6, S7 7}
+impl_multiproduct! {S0 0, S1 1, S2 2, S3 3, S4 4, S5 5, S6 6, S7 7, S8 8}
+impl_multiproduct! {S0 0, S1 1, S2 2, S3 3, S4 4, S5 5, S6 6, S7 7, S8 8, S9 9}
+impl_multiproduct! {S0 0, S1 1, S2 2, S3 3, S4 4, S5 5, S6 6, S7 7, S8 8, S9 9, S10 10}
+impl_multiproduct! {S0 0, S1 1, S2 2, S3 3, S4 4, S5 5, S6 6, S7 7, S8 8, S9 9, S10 10, S11 11}
+
+#[cfg(test)]
+mod tests {
+    use super::{multiproduct, MultiProduct, Product};
+    use crate::traits::*;
+
+    #[test]
+    fn new() {
+        assert!(Product::new(0..3, 0..2).is_some());
+        assert!(Product::new(0..usize::MAX, 0..0).is_some());
+        assert!(Product::new(0..usize::MAX, 0..2).is_none());
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(Product::new(0..3, 0..2).unwrap().len(), 6);
+        assert_eq!(Product::new(0..3, 0..0).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(!Product::new(0..3, 0..2).unwrap().is_empty());
+        assert!(Product::new(0..0, 0..2).unwrap().is_empty());
+        assert!(Product::new(0..3, 0..0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let x = Product::new(0..3, *b"ab").unwrap();
+        assert_eq!(x.get(0), Some((0, &b'a')));
+        assert_eq!(x.get(1), Some((0, &b'b')));
+        assert_eq!(x.get(2), Some((1, &b'a')));
+        assert_eq!(x.get(5), Some((2, &b'b')));
+        assert_eq!(x.get(6), None);
+        let y = Product::new(0..3, 0..0).unwrap();
+        assert_eq!(y.get(0), None);
+    }
+
+    #[test]
+    fn rget() {
+        let x = Product::new(0..3, *b"ab").unwrap();
+        assert_eq!(x.rget(0), Some((2, &b'b')));
+        assert_eq!(x.rget(1), Some((2, &b'a')));
+        assert_eq!(x.rget(6), None);
+    }
+
+    #[test]
+    fn first() {
+        assert_eq!(Product::new(0..3, 2..4).unwrap().first(), Some((0, 2)));
+        assert_eq!(Product::new(0..3, 0..0).unwrap().first(), None);
+    }
+
+    #[test]
+    fn last() {
+        assert_eq!(Product::new(0..3, 2..4).unwrap().last(), Some((2, 3)));
+        assert_eq!(Product::new(0..0, 2..4).unwrap().last(), None);
+    }
+
+    #[test]
+    fn iter() {
+        let x = Product::new(0..2, 2..4).unwrap();
+        assert!(x.iter().eq([(0, 2), (0, 3), (1, 2), (1, 3)]));
+        assert!(x.iter().rev().eq([(1, 3), (1, 2), (0, 3), (0, 2)]));
+    }
+
+    #[test]
+    fn multi_new() {
+        assert!(MultiProduct::new((0..3,)).is_some());
+        assert!(MultiProduct::new((0..3, 0..2, 0..4)).is_some());
+        assert!(MultiProduct::new((0..usize::MAX, 0..2, 0..1)).is_none());
+        assert!(MultiProduct::new((0..usize::MAX, 0..2, 0..0)).is_some());
+    }
+
+    #[test]
+    fn multi_len() {
+        assert_eq!(multiproduct((0..3, 0..2, 0..4)).unwrap().len(), 24);
+        assert_eq!(multiproduct((0..3, 0..0, 0..4)).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn multi_get() {
+        let x = multiproduct((0..2, 2..5, 5..7)).unwrap();
+        assert_eq!(x.get(0), Some((0, 2, 5)));
+        assert_eq!(x.get(1), Some((0, 2, 6)));
+        assert_eq!(x.get(2), Some((0, 3, 5)));
+        assert_eq!(x.get(6), Some((1, 2, 5)));
+        assert_eq!(x.get(11), Some((1, 4, 6)));
+        assert_eq!(x.get(12), None);
+        let y = multiproduct((0..2, 0..0)).unwrap();
+        assert_eq!(y.get(0), None);
+    }
+
+    #[test]
+    fn multi_iter() {
+        let x = multiproduct((0..2, 2..4, 4..5)).unwrap();
+        assert!(x.iter().eq([(0, 2, 4), (0, 3, 4), (1, 2, 4), (1, 3, 4)]));
+        assert!(x
+            .iter()
+            .rev()
+            .eq([(1, 3, 4), (1, 2, 4), (0, 3, 4), (0, 2, 4)]));
+    }
+}
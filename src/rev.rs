@@ -114,6 +114,9 @@ where
 // SAFETY: Any permutation of a unique sequence is unique.
 unsafe impl<Seq> UniqueSequence for Rev<Seq> where Seq: UniqueSequence {}
 
+// SAFETY: See above.
+unsafe impl<Seq> UniqueMutSequence for Rev<Seq> where Seq: UniqueMutSequence {}
+
 #[cfg(test)]
 mod tests {
     use super::Rev;
@@ -228,4 +231,24 @@ mod tests {
         assert!(iter.next().is_none());
         assert_eq!(x, [5, 6, 7]);
     }
+
+    #[test]
+    fn swap_reverse_rotate_fill() {
+        let mut x = [0, 1, 2, 3];
+        let mut y = Rev::new(x.as_mut_sqnc());
+        assert_eq!(y.sqnc_swap(0, 1), Some(()));
+        assert_eq!(x, [0, 1, 3, 2]);
+        let mut y = Rev::new(x.as_mut_sqnc());
+        y.sqnc_reverse();
+        assert_eq!(x, [2, 3, 1, 0]);
+        let mut y = Rev::new(x.as_mut_sqnc());
+        assert_eq!(y.sqnc_rotate_left(1), Some(()));
+        assert_eq!(x, [0, 2, 3, 1]);
+        let mut y = Rev::new(x.as_mut_sqnc());
+        assert_eq!(y.sqnc_rotate_right(3), Some(()));
+        assert_eq!(x, [1, 0, 2, 3]);
+        let mut y = Rev::new(x.as_mut_sqnc());
+        y.sqnc_fill(4);
+        assert_eq!(x, [4, 4, 4, 4]);
+    }
 }
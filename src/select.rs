@@ -39,7 +39,7 @@ where
 
 impl<'this, Seq, Idx> MutSequenceTypes<'this> for Select<Seq, Idx>
 where
-    Seq: MutSequence + UniqueSequence,
+    Seq: UniqueMutSequence,
     Idx: Sequence + for<'a> SequenceTypes<'a, Item = usize> + UniqueSequence,
     <Idx as SequenceTypes<'this>>::Iter: UniqueIterator,
 {
@@ -97,7 +97,7 @@ where
 
 impl<Seq, Idx> MutSequence for Select<Seq, Idx>
 where
-    Seq: MutSequence + UniqueSequence,
+    Seq: UniqueMutSequence,
     Idx: Sequence + for<'a> SequenceTypes<'a, Item = usize> + UniqueSequence,
     for<'a> <Idx as SequenceTypes<'a>>::Iter: UniqueIterator,
 {
@@ -191,7 +191,7 @@ pub struct SelectIterMut<'seq, Seq: ?Sized, IdxIter> {
 
 impl<'seq, Seq, IdxIter> Iterator for SelectIterMut<'seq, Seq, IdxIter>
 where
-    Seq: MutSequence + UniqueSequence + ?Sized,
+    Seq: UniqueMutSequence + ?Sized,
     IdxIter: Iterator<Item = usize> + UniqueIterator,
 {
     type Item = <Seq as MutSequenceTypes<'seq>>::MutItem;
@@ -199,8 +199,9 @@ where
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         let value = self.sequence.get_mut(self.indices.next()?)?;
-        // SAFETY: `Seq` implements `UniqueSequence`, which guarantees that
-        // `Seq::get_mut(index)` is unique for unique indices. If
+        // SAFETY: `Seq` implements `UniqueMutSequence`, which guarantees
+        // that `Seq::get_mut(index)` is unique for unique indices and does not
+        // invalidate elements previously returned for other indices. If
         // `Seq::MutItem` is a reference, uniqueness applies to the reference,
         // hence items returned by `Seq::get_mut()` don't alias for different
         // indices.
@@ -212,7 +213,12 @@ where
         //
         // The call to `transmute` replaces the life of value, currently the
         // lifetime of `&self`, with the lifetime `'seq`.
-        unsafe { Some(mem::transmute::<_, _>(value)) }
+        unsafe {
+            Some(mem::transmute::<
+                <Seq as MutSequenceTypes<'_>>::MutItem,
+                Self::Item,
+            >(value))
+        }
     }
 
     #[inline]
@@ -223,27 +229,32 @@ where
 
 impl<'seq, Seq, IdxIter> DoubleEndedIterator for SelectIterMut<'seq, Seq, IdxIter>
 where
-    Seq: MutSequence + UniqueSequence + ?Sized,
+    Seq: UniqueMutSequence + ?Sized,
     IdxIter: Iterator<Item = usize> + DoubleEndedIterator + UniqueIterator,
 {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
         let value = self.sequence.get_mut(self.indices.next_back()?)?;
         // SAFETY: See the impl of `Iterator` above.
-        unsafe { Some(mem::transmute::<_, _>(value)) }
+        unsafe {
+            Some(mem::transmute::<
+                <Seq as MutSequenceTypes<'_>>::MutItem,
+                Self::Item,
+            >(value))
+        }
     }
 }
 
 impl<'seq, Seq, IdxIter> ExactSizeIterator for SelectIterMut<'seq, Seq, IdxIter>
 where
-    Seq: MutSequence + UniqueSequence + ?Sized,
+    Seq: UniqueMutSequence + ?Sized,
     IdxIter: Iterator<Item = usize> + ExactSizeIterator + UniqueIterator,
 {
 }
 
 impl<'seq, Seq, IdxIter> FusedIterator for SelectIterMut<'seq, Seq, IdxIter>
 where
-    Seq: MutSequence + UniqueSequence + ?Sized,
+    Seq: UniqueMutSequence + ?Sized,
     IdxIter: Iterator<Item = usize> + FusedIterator + UniqueIterator,
 {
 }
@@ -256,6 +267,16 @@ where
 {
 }
 
+// SAFETY: A selection of a sequence with unique mutable elements with unique
+// indices has unique mutable elements.
+unsafe impl<Seq, Idx> UniqueMutSequence for Select<Seq, Idx>
+where
+    Seq: UniqueMutSequence,
+    Idx: Sequence + for<'a> SequenceTypes<'a, Item = usize> + UniqueSequence,
+    for<'a> <Idx as SequenceTypes<'a>>::Iter: UniqueIterator,
+{
+}
+
 // SAFETY: See above.
 unsafe impl<'seq, Seq, IdxIter> UniqueIterator for SelectIter<'seq, Seq, IdxIter>
 where
@@ -267,7 +288,7 @@ where
 // SAFETY: See above.
 unsafe impl<'seq, Seq, IdxIter> UniqueIterator for SelectIterMut<'seq, Seq, IdxIter>
 where
-    Seq: MutSequence + UniqueSequence + ?Sized,
+    Seq: UniqueMutSequence + ?Sized,
     IdxIter: Iterator<Item = usize> + UniqueIterator,
 {
 }
@@ -419,4 +440,24 @@ mod tests {
         iter.next();
         assert_eq!(iter.size_hint(), (0, Some(0)));
     }
+
+    #[test]
+    fn swap_reverse_rotate_fill() {
+        let mut x = [0, 1, 2, 3, 4, 5];
+        let mut y = Select::new(x.as_mut_sqnc(), Sequence::rev(1..5)).unwrap();
+        assert_eq!(y.sqnc_swap(0, 3), Some(()));
+        assert_eq!(x, [0, 4, 2, 3, 1, 5]);
+        let mut y = Select::new(x.as_mut_sqnc(), Sequence::rev(1..5)).unwrap();
+        y.sqnc_reverse();
+        assert_eq!(x, [0, 1, 3, 2, 4, 5]);
+        let mut y = Select::new(x.as_mut_sqnc(), Sequence::rev(1..5)).unwrap();
+        assert_eq!(y.sqnc_rotate_left(1), Some(()));
+        assert_eq!(x, [0, 4, 1, 3, 2, 5]);
+        let mut y = Select::new(x.as_mut_sqnc(), Sequence::rev(1..5)).unwrap();
+        assert_eq!(y.sqnc_rotate_right(1), Some(()));
+        assert_eq!(x, [0, 1, 3, 2, 4, 5]);
+        let mut y = Select::new(x.as_mut_sqnc(), Sequence::rev(1..5)).unwrap();
+        y.sqnc_fill(6);
+        assert_eq!(x, [0, 6, 6, 6, 6, 5]);
+    }
 }
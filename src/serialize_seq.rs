@@ -0,0 +1,134 @@
+use crate::traits::*;
+use serde::ser::{Serialize, SerializeSeq as _, Serializer};
+
+/// Wrapper that serializes the elements of a [`Sequence`].
+///
+/// The sequence is serialized as a serde sequence with known length, the same
+/// as a [`slice`] with the same elements. This allows serializing adaptors
+/// like [`Select`][`crate::Select`] and [`Map`][`crate::Map`] without
+/// collecting the elements first. Requires feature `serde`.
+///
+/// # Examples
+///
+/// ```
+/// use serde_test::{assert_ser_tokens, Token};
+/// use sqnc::{Sequence, SerializeSeq};
+///
+/// let x = [1, 2, 3, 4];
+/// let y = Sequence::map(x.select([3, 1].copied()).unwrap(), |v| v * 10);
+/// assert_ser_tokens(
+///     &SerializeSeq::new(&y),
+///     &[
+///         Token::Seq { len: Some(2) },
+///         Token::I32(40),
+///         Token::I32(20),
+///         Token::SeqEnd,
+///     ],
+/// );
+/// ```
+///
+/// Serializing a sequence field of a struct:
+///
+/// ```
+/// use serde::{Serialize, Serializer};
+/// use serde_test::{assert_ser_tokens, Token};
+/// use sqnc::{Arange, Sequence, SerializeSeq};
+///
+/// fn serialize_seq<S: Sequence, Ser: Serializer>(x: &S, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+/// where
+///     for<'a> <S as sqnc::SequenceTypes<'a>>::Item: Serialize,
+/// {
+///     SerializeSeq::new(x).serialize(serializer)
+/// }
+///
+/// #[derive(Serialize)]
+/// struct Data {
+///     #[serde(serialize_with = "serialize_seq")]
+///     x: Arange<u8>,
+/// }
+///
+/// assert_ser_tokens(
+///     &Data { x: Arange::new(1, 1, 2).unwrap() },
+///     &[
+///         Token::Struct { name: "Data", len: 1 },
+///         Token::Str("x"),
+///         Token::Seq { len: Some(2) },
+///         Token::U8(1),
+///         Token::U8(2),
+///         Token::SeqEnd,
+///         Token::StructEnd,
+///     ],
+/// );
+/// ```
+pub struct SerializeSeq<'a, S: ?Sized>(&'a S);
+
+impl<'a, S> SerializeSeq<'a, S>
+where
+    S: Sequence + ?Sized,
+{
+    /// Wraps a reference to a sequence.
+    #[inline]
+    pub fn new(sequence: &'a S) -> Self {
+        Self(sequence)
+    }
+}
+
+impl<'a, S> Serialize for SerializeSeq<'a, S>
+where
+    S: Sequence + ?Sized,
+    for<'b> <S as SequenceTypes<'b>>::Item: Serialize,
+{
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for item in self.0.iter() {
+            seq.serialize_element(&item)?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SerializeSeq;
+    use crate::traits::*;
+    use serde_test::{assert_ser_tokens, Token};
+
+    #[test]
+    fn array() {
+        assert_ser_tokens(
+            &SerializeSeq::new(&[1u8, 2]),
+            &[
+                Token::Seq { len: Some(2) },
+                Token::U8(1),
+                Token::U8(2),
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn empty() {
+        assert_ser_tokens(
+            &SerializeSeq::new(&[0u8; 0]),
+            &[Token::Seq { len: Some(0) }, Token::SeqEnd],
+        );
+    }
+
+    #[test]
+    fn select() {
+        let x = *b"abcd";
+        let y = x.select([3, 0].copied()).unwrap();
+        assert_ser_tokens(
+            &SerializeSeq::new(&y),
+            &[
+                Token::Seq { len: Some(2) },
+                Token::U8(b'd'),
+                Token::U8(b'a'),
+                Token::SeqEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,541 @@
+use crate::derive::Iter;
+use crate::traits::*;
+use crate::Wrapper;
+use core::iter::{self, FusedIterator};
+use core::marker::PhantomData;
+use core::ops::{Bound, Range, RangeBounds};
+
+/// A contiguous subsequence of a sequence.
+///
+/// This struct is created by [`Sequence::sqnc_slice()`]. See its documentation
+/// for more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slice<Seq> {
+    seq: Seq,
+    start: usize,
+    len: usize,
+}
+
+impl<Seq> Slice<Seq>
+where
+    Seq: Sequence,
+{
+    #[inline]
+    pub(crate) fn new<R>(seq: Seq, range: R) -> Option<Self>
+    where
+        R: RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1)?,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => seq.len(),
+        };
+        if start <= end && end <= seq.len() {
+            Some(Self {
+                seq,
+                start,
+                len: end - start,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'this, Seq> SequenceTypes<'this> for Slice<Seq>
+where
+    Seq: Sequence,
+{
+    type Item = <Seq as SequenceTypes<'this>>::Item;
+    type Iter = Iter<'this, Self>;
+}
+
+impl<'this, Seq> MutSequenceTypes<'this> for Slice<Seq>
+where
+    Seq: MutSequence,
+{
+    type MutItem = <Seq as MutSequenceTypes<'this>>::MutItem;
+    type IterMut = iter::Take<iter::Skip<<Seq as MutSequenceTypes<'this>>::IterMut>>;
+}
+
+impl<Seq> Sequence for Slice<Seq>
+where
+    Seq: Sequence,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<<Self as SequenceTypes<'_>>::Item> {
+        if index < self.len {
+            self.seq.get(self.start + index)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn rget(&self, rindex: usize) -> Option<<Self as SequenceTypes<'_>>::Item> {
+        if rindex < self.len {
+            self.seq.get(self.start + (self.len - 1 - rindex))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn iter(&self) -> Iter<'_, Self> {
+        self.into()
+    }
+}
+
+impl<Seq> MutSequence for Slice<Seq>
+where
+    Seq: MutSequence,
+{
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<<Self as MutSequenceTypes<'_>>::MutItem> {
+        if index < self.len {
+            self.seq.get_mut(self.start + index)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn rget_mut(&mut self, rindex: usize) -> Option<<Self as MutSequenceTypes<'_>>::MutItem> {
+        if rindex < self.len {
+            self.seq.get_mut(self.start + (self.len - 1 - rindex))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn iter_mut(&mut self) -> <Self as MutSequenceTypes<'_>>::IterMut {
+        self.seq.iter_mut().skip(self.start).take(self.len)
+    }
+}
+
+impl<'seq, Seq> IntoIterator for Slice<Wrapper<&'seq Seq, ((),)>>
+where
+    Seq: Sequence,
+{
+    type Item = <Seq as SequenceTypes<'seq>>::Item;
+    type IntoIter = Iter<'seq, Seq>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'seq, Seq> {
+        Iter::with_range(self.seq.unwrap(), self.start..self.start + self.len)
+    }
+}
+
+// SAFETY: A contiguous subsequence of a unique sequence is unique.
+unsafe impl<Seq> UniqueSequence for Slice<Seq> where Seq: UniqueSequence {}
+
+// SAFETY: See above.
+unsafe impl<Seq> UniqueMutSequence for Slice<Seq> where Seq: UniqueMutSequence {}
+
+/// A mutable contiguous subsequence that shares the parent sequence with other subsequences.
+///
+/// Unlike [`Slice`], [`SliceMut`] does not hold a mutable reference to the
+/// parent sequence, but a pointer. This allows several subsequences with
+/// disjoint ranges of indices, e.g. the chunks returned by
+/// [`MutSequence::sqnc_chunks_mut()`] or the subsequences returned by
+/// [`MutSequence::sqnc_split_at_mut()`], to be mutated independently. The
+/// parent sequence must implement [`UniqueMutSequence`], such that the
+/// elements of different subsequences don't alias.
+pub struct SliceMut<'seq, Seq: ?Sized> {
+    seq: *mut Seq,
+    start: usize,
+    len: usize,
+    phantom: PhantomData<&'seq mut Seq>,
+}
+
+impl<'seq, Seq> SliceMut<'seq, Seq>
+where
+    Seq: UniqueMutSequence + ?Sized,
+{
+    /// Returns the subsequence of `seq` with the given range of indices.
+    ///
+    /// # Safety
+    ///
+    /// `seq` must be valid for `'seq` and `range` must be in bounds. During
+    /// `'seq` the parent sequence must not be accessed other than via
+    /// subsequences with disjoint ranges of indices.
+    #[inline]
+    pub(crate) unsafe fn new(seq: *mut Seq, range: Range<usize>) -> Self {
+        Self {
+            seq,
+            start: range.start,
+            len: range.end - range.start,
+            phantom: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn seq(&self) -> &Seq {
+        // SAFETY: See `SliceMut::new()`. The parent sequence is accessed
+        // only for the duration of a call to `Sequence::get()` with an index
+        // in range.
+        unsafe { &*self.seq }
+    }
+
+    #[inline]
+    fn seq_mut(&mut self) -> &mut Seq {
+        // SAFETY: See `SliceMut::new()`. The parent sequence is accessed
+        // only for the duration of a call to `MutSequence::get_mut()` with an
+        // index in range. Since `Seq` implements `UniqueMutSequence`, the
+        // returned elements don't alias those of other subsequences and
+        // remain valid.
+        unsafe { &mut *self.seq }
+    }
+}
+
+impl<'this, 'seq, Seq> SequenceTypes<'this> for SliceMut<'seq, Seq>
+where
+    Seq: UniqueMutSequence + ?Sized,
+{
+    type Item = <Seq as SequenceTypes<'this>>::Item;
+    type Iter = Iter<'this, Self>;
+}
+
+impl<'this, 'seq, Seq> MutSequenceTypes<'this> for SliceMut<'seq, Seq>
+where
+    Seq: UniqueMutSequence + ?Sized,
+{
+    type MutItem = <Seq as MutSequenceTypes<'this>>::MutItem;
+    type IterMut = SliceIterMut<'this, Seq>;
+}
+
+impl<'seq, Seq> Sequence for SliceMut<'seq, Seq>
+where
+    Seq: UniqueMutSequence + ?Sized,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<<Self as SequenceTypes<'_>>::Item> {
+        if index < self.len {
+            self.seq().get(self.start + index)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn iter(&self) -> Iter<'_, Self> {
+        self.into()
+    }
+}
+
+impl<'seq, Seq> MutSequence for SliceMut<'seq, Seq>
+where
+    Seq: UniqueMutSequence + ?Sized,
+{
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<<Self as MutSequenceTypes<'_>>::MutItem> {
+        if index < self.len {
+            let index = self.start + index;
+            self.seq_mut().get_mut(index)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn iter_mut(&mut self) -> SliceIterMut<'_, Seq> {
+        SliceIterMut {
+            seq: self.seq,
+            index: self.start..self.start + self.len,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'seq, Seq> IntoIterator for SliceMut<'seq, Seq>
+where
+    Seq: UniqueMutSequence + ?Sized,
+{
+    type Item = <Seq as MutSequenceTypes<'seq>>::MutItem;
+    type IntoIter = SliceIterMut<'seq, Seq>;
+
+    #[inline]
+    fn into_iter(self) -> SliceIterMut<'seq, Seq> {
+        SliceIterMut {
+            seq: self.seq,
+            index: self.start..self.start + self.len,
+            phantom: PhantomData,
+        }
+    }
+}
+
+// SAFETY: A contiguous subsequence of a unique sequence is unique.
+unsafe impl<'seq, Seq> UniqueSequence for SliceMut<'seq, Seq> where
+    Seq: UniqueMutSequence + UniqueSequence + ?Sized
+{
+}
+
+// SAFETY: See above.
+unsafe impl<'seq, Seq> UniqueMutSequence for SliceMut<'seq, Seq> where
+    Seq: UniqueMutSequence + ?Sized
+{
+}
+
+pub struct SliceIterMut<'seq, Seq: ?Sized> {
+    seq: *mut Seq,
+    index: Range<usize>,
+    phantom: PhantomData<&'seq mut Seq>,
+}
+
+impl<'seq, Seq> SliceIterMut<'seq, Seq>
+where
+    Seq: UniqueMutSequence + ?Sized,
+{
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<<Seq as MutSequenceTypes<'seq>>::MutItem> {
+        // SAFETY: The iterator borrows a `SliceMut` mutably for `'seq` and
+        // visits every index in range of the subsequence at most once. Since
+        // `Seq` implements `UniqueMutSequence`, the returned elements don't
+        // alias.
+        let seq: &'seq mut Seq = unsafe { &mut *self.seq };
+        seq.get_mut(index)
+    }
+}
+
+impl<'seq, Seq> Iterator for SliceIterMut<'seq, Seq>
+where
+    Seq: UniqueMutSequence + ?Sized,
+{
+    type Item = <Seq as MutSequenceTypes<'seq>>::MutItem;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.index.next()?;
+        self.get_mut(index)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let index = self.index.nth(n)?;
+        self.get_mut(index)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.index.size_hint()
+    }
+}
+
+impl<'seq, Seq> DoubleEndedIterator for SliceIterMut<'seq, Seq>
+where
+    Seq: UniqueMutSequence + ?Sized,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.index.next_back()?;
+        self.get_mut(index)
+    }
+}
+
+impl<'seq, Seq> ExactSizeIterator for SliceIterMut<'seq, Seq> where Seq: UniqueMutSequence + ?Sized {}
+
+impl<'seq, Seq> FusedIterator for SliceIterMut<'seq, Seq> where Seq: UniqueMutSequence + ?Sized {}
+
+// SAFETY: See the impl of `UniqueMutSequence` for `SliceMut`.
+unsafe impl<'seq, Seq> UniqueIterator for SliceIterMut<'seq, Seq> where
+    Seq: UniqueMutSequence + ?Sized
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Slice, SliceMut};
+    use crate::traits::*;
+    use core::ops::Bound;
+
+    #[test]
+    fn new() {
+        assert!(Slice::new(2..6, 1..3).is_some());
+        assert!(Slice::new(2..6, 1..=3).is_some());
+        assert!(Slice::new(2..6, ..).is_some());
+        assert!(Slice::new(2..6, 4..).is_some());
+        assert!(Slice::new(2..6, ..=4).is_none());
+        assert!(Slice::new(2..6, 5..).is_none());
+        assert!(Slice::new(2..6, (Bound::Included(3), Bound::Excluded(1))).is_none());
+        assert!(Slice::new(0..usize::MAX, ..=usize::MAX).is_none());
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(Slice::new(2..6, 1..3).unwrap().len(), 2);
+        assert_eq!(Slice::new(2..6, 1..=3).unwrap().len(), 3);
+        assert_eq!(Slice::new(2..6, ..).unwrap().len(), 4);
+        assert_eq!(Slice::new(2..6, 4..).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(!Slice::new(2..6, 1..3).unwrap().is_empty());
+        assert!(Slice::new(2..6, 1..1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let x = Slice::new(2..6, 1..3).unwrap();
+        assert_eq!(x.get(0), Some(3));
+        assert_eq!(x.get(1), Some(4));
+        assert_eq!(x.get(2), None);
+    }
+
+    #[test]
+    fn rget() {
+        let x = Slice::new(2..6, 1..3).unwrap();
+        assert_eq!(x.rget(0), Some(4));
+        assert_eq!(x.rget(1), Some(3));
+        assert_eq!(x.rget(2), None);
+    }
+
+    #[test]
+    fn first() {
+        assert_eq!(Slice::new(2..6, 1..3).unwrap().first(), Some(3));
+        assert_eq!(Slice::new(2..6, 1..1).unwrap().first(), None);
+    }
+
+    #[test]
+    fn last() {
+        assert_eq!(Slice::new(2..6, 1..3).unwrap().last(), Some(4));
+        assert_eq!(Slice::new(2..6, 1..1).unwrap().last(), None);
+    }
+
+    #[test]
+    fn iter() {
+        assert!(Slice::new(2..6, 1..3).unwrap().iter().eq(3..5));
+        assert!(Slice::new(2..6, 1..1).unwrap().iter().eq(0..0));
+    }
+
+    #[test]
+    fn rev_iter() {
+        let x = Slice::new(2..6, 1..3).unwrap();
+        assert!(x.iter().rev().eq([4, 3]));
+    }
+
+    #[test]
+    fn get_mut() {
+        let mut x = [2, 3, 4, 5];
+        let mut y = Slice::new(x.as_mut_sqnc(), 1..3).unwrap();
+        *y.get_mut(0).unwrap() = 6;
+        *y.get_mut(1).unwrap() = 7;
+        assert!(y.get_mut(2).is_none());
+        assert_eq!(x, [2, 6, 7, 5]);
+    }
+
+    #[test]
+    fn rget_mut() {
+        let mut x = [2, 3, 4, 5];
+        let mut y = Slice::new(x.as_mut_sqnc(), 1..3).unwrap();
+        *y.rget_mut(0).unwrap() = 6;
+        *y.rget_mut(1).unwrap() = 7;
+        assert!(y.rget_mut(2).is_none());
+        assert_eq!(x, [2, 7, 6, 5]);
+    }
+
+    #[test]
+    fn first_mut() {
+        let mut x = [2, 3, 4, 5];
+        *Slice::new(x.as_mut_sqnc(), 1..3)
+            .unwrap()
+            .first_mut()
+            .unwrap() = 6;
+        assert_eq!(x, [2, 6, 4, 5]);
+        assert!(Slice::new(x.as_mut_sqnc(), 1..1)
+            .unwrap()
+            .first_mut()
+            .is_none());
+    }
+
+    #[test]
+    fn last_mut() {
+        let mut x = [2, 3, 4, 5];
+        *Slice::new(x.as_mut_sqnc(), 1..3)
+            .unwrap()
+            .last_mut()
+            .unwrap() = 6;
+        assert_eq!(x, [2, 3, 6, 5]);
+        assert!(Slice::new(x.as_mut_sqnc(), 1..1)
+            .unwrap()
+            .last_mut()
+            .is_none());
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut x = [2, 3, 4, 5];
+        let mut y = Slice::new(x.as_mut_sqnc(), 1..3).unwrap();
+        y.iter_mut().for_each(|v| *v += 3);
+        assert_eq!(x, [2, 6, 7, 5]);
+    }
+
+    #[test]
+    fn rev_iter_mut() {
+        let mut x = [2, 3, 4, 5];
+        let mut y = Slice::new(x.as_mut_sqnc(), 1..3).unwrap();
+        let mut iter = y.iter_mut().rev();
+        *iter.next().unwrap() = 6;
+        *iter.next().unwrap() = 7;
+        assert!(iter.next().is_none());
+        assert_eq!(x, [2, 7, 6, 5]);
+    }
+
+    #[test]
+    fn iter_mut_size_hint() {
+        let mut x = [2, 3, 4, 5];
+        let mut y = Slice::new(x.as_mut_sqnc(), 1..3).unwrap();
+        let mut iter = y.iter_mut();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        iter.next();
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+    }
+
+    #[test]
+    fn slice_mut() {
+        let mut x = [2, 3, 4, 5];
+        let ptr: *mut _ = &mut x;
+        // SAFETY: `x` is not accessed while `y` and `z` are alive and the
+        // ranges are disjoint.
+        let (mut y, mut z) = unsafe { (SliceMut::new(ptr, 0..1), SliceMut::new(ptr, 1..4)) };
+        assert_eq!(y.len(), 1);
+        assert_eq!(z.len(), 3);
+        assert_eq!(z.get(0), Some(&3));
+        assert!(z.get(3).is_none());
+        assert!(z.iter().eq(&[3, 4, 5]));
+        let y0 = y.get_mut(0).unwrap();
+        z.iter_mut().for_each(|v| *v += *y0);
+        *y0 = 0;
+        assert!(y.get_mut(1).is_none());
+        assert_eq!(x, [0, 5, 6, 7]);
+    }
+
+    #[test]
+    fn slice_mut_rev_iter_mut() {
+        let mut x = [2, 3, 4, 5];
+        // SAFETY: `x` is not accessed while `y` is alive.
+        let mut y = unsafe { SliceMut::new(&mut x as *mut [i32; 4], 1..3) };
+        let mut iter = y.iter_mut().rev();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        *iter.next().unwrap() = 6;
+        *iter.next().unwrap() = 7;
+        assert!(iter.next().is_none());
+        assert_eq!(x, [2, 7, 6, 5]);
+    }
+}
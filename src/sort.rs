@@ -0,0 +1,278 @@
+//! Swap-based algorithms for mutable sequences.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+use crate::traits::*;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::mem;
+
+/// Returns mutable references to the elements at two distinct indices or
+/// `None` if the indices are equal or out of bounds.
+#[inline]
+pub(crate) fn get_pair_mut<S, T>(seq: &mut S, i: usize, j: usize) -> Option<(&mut T, &mut T)>
+where
+    S: UniqueMutSequence + for<'a> MutSequenceTypes<'a, MutItem = &'a mut T> + ?Sized,
+{
+    if i == j {
+        return None;
+    }
+    let a: *mut T = seq.get_mut(i)?;
+    let b: *mut T = seq.get_mut(j)?;
+    // SAFETY: Per the safety contract of `UniqueMutSequence`, `S::get_mut()`
+    // returns unique elements for unique indices and does not modify the
+    // sequence other than via the returned element. Since `i != j`, `a` is
+    // still valid after the second call and `a` and `b` don't alias.
+    Some(unsafe { (&mut *a, &mut *b) })
+}
+
+/// Swaps the elements at two indices. Returns `None` if any of the indices
+/// is out of bounds.
+#[inline]
+pub(crate) fn swap<S, T>(seq: &mut S, i: usize, j: usize) -> Option<()>
+where
+    S: UniqueMutSequence + for<'a> MutSequenceTypes<'a, MutItem = &'a mut T> + ?Sized,
+{
+    if i == j {
+        return (i < seq.len()).then_some(());
+    }
+    let (a, b) = get_pair_mut(seq, i, j)?;
+    mem::swap(a, b);
+    Some(())
+}
+
+/// Reverses the order of the elements in `start..end`. Returns `None` if the
+/// range is out of bounds.
+pub(crate) fn reverse_range<S, T>(seq: &mut S, start: usize, end: usize) -> Option<()>
+where
+    S: UniqueMutSequence + for<'a> MutSequenceTypes<'a, MutItem = &'a mut T> + ?Sized,
+{
+    if start > end || end > seq.len() {
+        return None;
+    }
+    for i in 0..(end - start) / 2 {
+        swap(seq, start + i, end - 1 - i)?;
+    }
+    Some(())
+}
+
+/// Rotates the sequence such that the element at index `mid` becomes the
+/// first element. Returns `None` if `mid` is larger than the length of the
+/// sequence.
+pub(crate) fn rotate_left<S, T>(seq: &mut S, mid: usize) -> Option<()>
+where
+    S: UniqueMutSequence + for<'a> MutSequenceTypes<'a, MutItem = &'a mut T> + ?Sized,
+{
+    let len = seq.len();
+    if mid > len {
+        return None;
+    }
+    reverse_range(seq, 0, mid)?;
+    reverse_range(seq, mid, len)?;
+    reverse_range(seq, 0, len)
+}
+
+/// Returns `true` if the element at index `i` is less than the element at
+/// index `j`.
+#[inline]
+fn is_less_at<S, T, F>(seq: &mut S, i: usize, j: usize, is_less: &mut F) -> bool
+where
+    S: UniqueMutSequence + for<'a> MutSequenceTypes<'a, MutItem = &'a mut T> + ?Sized,
+    F: FnMut(&T, &T) -> bool,
+{
+    get_pair_mut(seq, i, j).map_or(false, |(a, b)| is_less(a, b))
+}
+
+/// Restores the heap property for the subtree rooted at `node`, considering
+/// only the first `end` elements.
+fn sift_down<S, T, F>(seq: &mut S, mut node: usize, end: usize, is_less: &mut F)
+where
+    S: UniqueMutSequence + for<'a> MutSequenceTypes<'a, MutItem = &'a mut T> + ?Sized,
+    F: FnMut(&T, &T) -> bool,
+{
+    loop {
+        let mut child = 2 * node + 1;
+        if child >= end {
+            break;
+        }
+        if child + 1 < end && is_less_at(seq, child, child + 1, is_less) {
+            child += 1;
+        }
+        if !is_less_at(seq, node, child, is_less) {
+            break;
+        }
+        // `node < child < end <= len`, hence the swap cannot fail.
+        let swapped = swap(seq, node, child);
+        debug_assert!(swapped.is_some());
+        node = child;
+    }
+}
+
+/// Sorts the sequence in place using heapsort.
+///
+/// This sort is unstable, does not allocate and has a worst case complexity
+/// of *O*(*n* \* log(*n*)).
+pub(crate) fn heapsort<S, T, F>(seq: &mut S, mut is_less: F)
+where
+    S: UniqueMutSequence + for<'a> MutSequenceTypes<'a, MutItem = &'a mut T> + ?Sized,
+    F: FnMut(&T, &T) -> bool,
+{
+    let len = seq.len();
+    for node in Iterator::rev(0..len / 2) {
+        sift_down(seq, node, len, &mut is_less);
+    }
+    for end in Iterator::rev(1..len) {
+        // `0 < end < len`, hence the swap cannot fail.
+        let swapped = swap(seq, 0, end);
+        debug_assert!(swapped.is_some());
+        sift_down(seq, 0, end, &mut is_less);
+    }
+}
+
+/// Sorts the sequence in place using a stable sort.
+///
+/// The sort order is computed on a vector of references to the elements,
+/// after which the elements are moved into place by following the cycles of
+/// the resulting permutation.
+#[cfg(feature = "alloc")]
+pub(crate) fn stable_sort<S, T, F>(seq: &mut S, mut is_less: F)
+where
+    S: MutSequence + for<'a> MutSequenceTypes<'a, MutItem = &'a mut T> + ?Sized,
+    F: FnMut(&T, &T) -> bool,
+{
+    let mut elems: Vec<&mut T> = seq.iter_mut().collect();
+    // `perm[k]` is the index of the element that should be moved to `k`.
+    let mut perm: Vec<usize> = (0..elems.len()).collect();
+    perm.sort_by(|&i, &j| {
+        if is_less(&*elems[i], &*elems[j]) {
+            core::cmp::Ordering::Less
+        } else if is_less(&*elems[j], &*elems[i]) {
+            core::cmp::Ordering::Greater
+        } else {
+            core::cmp::Ordering::Equal
+        }
+    });
+    for start in 0..perm.len() {
+        let mut k = start;
+        loop {
+            let next = mem::replace(&mut perm[k], k);
+            if next == start {
+                break;
+            }
+            // `next` is the successor of `k` in a cycle of length two or more,
+            // hence `k != next`.
+            let (lo, hi) = elems.split_at_mut(k.max(next));
+            mem::swap(lo[k.min(next)], hi[0]);
+            k = next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{get_pair_mut, heapsort, reverse_range, rotate_left, swap};
+    use crate::traits::*;
+
+    #[test]
+    fn get_pair_mut_distinct() {
+        let mut x = [1, 2, 3];
+        let (a, b) = get_pair_mut(&mut x, 2, 0).unwrap();
+        assert_eq!((*a, *b), (3, 1));
+        assert!(get_pair_mut(&mut x, 1, 1).is_none());
+        assert!(get_pair_mut(&mut x, 1, 3).is_none());
+    }
+
+    #[test]
+    fn swap_elements() {
+        let mut x = [1, 2, 3];
+        assert_eq!(swap(&mut x, 0, 2), Some(()));
+        assert_eq!(x, [3, 2, 1]);
+        assert_eq!(swap(&mut x, 1, 1), Some(()));
+        assert_eq!(swap(&mut x, 3, 3), None);
+        assert_eq!(swap(&mut x, 0, 3), None);
+        assert_eq!(x, [3, 2, 1]);
+    }
+
+    #[test]
+    fn heapsort_permutations() {
+        // Sort every permutation of a small sequence with duplicates.
+        let values = [0, 1, 1, 2, 3, 5];
+        for mut state in 0..720usize {
+            let mut pool = values;
+            let mut x = [0; 6];
+            for (i, x) in x.iter_mut().enumerate() {
+                let j = state % (6 - i);
+                state /= 6 - i;
+                *x = pool[j];
+                pool.copy_within(j + 1.., j);
+            }
+            heapsort(&mut x.as_mut_sqnc().rev(), |a: &usize, b: &usize| a < b);
+            assert_eq!(x, [5, 3, 2, 1, 1, 0]);
+        }
+    }
+
+    #[test]
+    fn sort_unstable_concat() {
+        let mut x = [4, 1, 5];
+        let mut y = [0, 3, 2];
+        let mut z = x.as_mut_sqnc().concat(y.as_mut_sqnc()).unwrap();
+        z.sqnc_sort_unstable();
+        assert_eq!(x, [0, 1, 2]);
+        assert_eq!(y, [3, 4, 5]);
+        let mut z = x.as_mut_sqnc().concat(y.as_mut_sqnc()).unwrap();
+        z.sqnc_sort_unstable_by(|a, b| b.cmp(a));
+        assert_eq!(x, [5, 4, 3]);
+        assert_eq!(y, [2, 1, 0]);
+    }
+
+    #[test]
+    fn sort_unstable_select() {
+        let mut x = [5, 4, 3, 2, 1, 0];
+        let idx = (1..6).sqnc_step_by(2).unwrap().rev();
+        let mut y = x.as_mut_sqnc().select(idx).unwrap();
+        y.sqnc_sort_unstable_by_key(|v: &i32| -v);
+        assert_eq!(x, [5, 0, 3, 2, 1, 4]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sort_concat() {
+        let mut x = [(1, 'a'), (0, 'b')];
+        let mut y = [(1, 'c'), (0, 'd')];
+        let mut z = x.as_mut_sqnc().concat(y.as_mut_sqnc()).unwrap();
+        z.sqnc_sort_by_key(|v| v.0);
+        assert_eq!(x, [(0, 'b'), (0, 'd')]);
+        assert_eq!(y, [(1, 'a'), (1, 'c')]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sort_select() {
+        let mut x = [5, 4, 3, 2, 1, 0];
+        let idx = (0..6).sqnc_step_by(2).unwrap();
+        let mut y = x.as_mut_sqnc().select(idx).unwrap();
+        y.sqnc_sort();
+        assert_eq!(x, [1, 4, 3, 2, 5, 0]);
+    }
+
+    #[test]
+    fn reverse_range_elements() {
+        let mut x = [0, 1, 2, 3, 4];
+        assert_eq!(reverse_range(&mut x, 1, 4), Some(()));
+        assert_eq!(x, [0, 3, 2, 1, 4]);
+        assert_eq!(reverse_range(&mut x, 2, 2), Some(()));
+        assert_eq!(reverse_range(&mut x, 3, 2), None);
+        assert_eq!(reverse_range(&mut x, 0, 6), None);
+        assert_eq!(x, [0, 3, 2, 1, 4]);
+    }
+
+    #[test]
+    fn rotate_left_elements() {
+        for mid in 0..=5 {
+            let mut x = [0, 1, 2, 3, 4];
+            assert_eq!(rotate_left(&mut x, mid), Some(()));
+            assert!(x.iter().copied().eq((mid..5).chain(0..mid)));
+        }
+        assert_eq!(rotate_left(&mut [0, 1, 2], 4), None);
+    }
+}
@@ -0,0 +1,237 @@
+use crate::traits::*;
+use core::iter;
+
+/// A sequence that steps over a sequence by a fixed amount.
+///
+/// This struct is created by [`Sequence::sqnc_step_by()`]. See its documentation
+/// for more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StepBy<Seq> {
+    seq: Seq,
+    step: usize,
+    len: usize,
+}
+
+impl<Seq> StepBy<Seq>
+where
+    Seq: Sequence,
+{
+    #[inline]
+    pub(crate) fn new(seq: Seq, step: usize) -> Option<Self> {
+        let n = seq.len();
+        let len = n.checked_div(step)? + usize::from(n % step != 0);
+        Some(Self { seq, step, len })
+    }
+}
+
+impl<'this, Seq> SequenceTypes<'this> for StepBy<Seq>
+where
+    Seq: SequenceTypes<'this>,
+{
+    type Item = Seq::Item;
+    type Iter = iter::Fuse<iter::StepBy<Seq::Iter>>;
+}
+
+impl<'this, Seq> MutSequenceTypes<'this> for StepBy<Seq>
+where
+    Seq: MutSequenceTypes<'this>,
+{
+    type MutItem = Seq::MutItem;
+    type IterMut = iter::Fuse<iter::StepBy<Seq::IterMut>>;
+}
+
+impl<Seq> Sequence for StepBy<Seq>
+where
+    Seq: Sequence,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<<Self as SequenceTypes<'_>>::Item> {
+        if index < self.len {
+            self.seq.get(index * self.step)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn rget(&self, rindex: usize) -> Option<<Self as SequenceTypes<'_>>::Item> {
+        if rindex < self.len {
+            self.seq.get((self.len - 1 - rindex) * self.step)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn iter(&self) -> <Self as SequenceTypes<'_>>::Iter {
+        self.seq.iter().step_by(self.step).fuse()
+    }
+}
+
+impl<Seq> MutSequence for StepBy<Seq>
+where
+    Seq: MutSequence,
+{
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<<Self as MutSequenceTypes<'_>>::MutItem> {
+        if index < self.len {
+            self.seq.get_mut(index * self.step)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn rget_mut(&mut self, rindex: usize) -> Option<<Self as MutSequenceTypes<'_>>::MutItem> {
+        if rindex < self.len {
+            self.seq.get_mut((self.len - 1 - rindex) * self.step)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn iter_mut(&mut self) -> <Self as MutSequenceTypes<'_>>::IterMut {
+        self.seq.iter_mut().step_by(self.step).fuse()
+    }
+}
+
+// SAFETY: A subset of a unique sequence is unique.
+unsafe impl<Seq> UniqueSequence for StepBy<Seq> where Seq: UniqueSequence {}
+
+// SAFETY: See above.
+unsafe impl<Seq> UniqueMutSequence for StepBy<Seq> where Seq: UniqueMutSequence {}
+
+#[cfg(test)]
+mod tests {
+    use super::StepBy;
+    use crate::traits::*;
+
+    #[test]
+    fn new() {
+        assert!(StepBy::new(0..3, 2).is_some());
+        assert!(StepBy::new(0..3, 0).is_none());
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(StepBy::new(0..6, 2).unwrap().len(), 3);
+        assert_eq!(StepBy::new(0..7, 2).unwrap().len(), 4);
+        assert_eq!(StepBy::new(0..7, 1).unwrap().len(), 7);
+        assert_eq!(StepBy::new(0..1, 3).unwrap().len(), 1);
+        assert_eq!(StepBy::new(0..0, 3).unwrap().len(), 0);
+        assert_eq!(
+            StepBy::new(0..usize::MAX, 2).unwrap().len(),
+            usize::MAX / 2 + 1
+        );
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(!StepBy::new(0..1, 2).unwrap().is_empty());
+        assert!(StepBy::new(0..0, 2).unwrap().is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let x = StepBy::new(2..9, 3).unwrap();
+        assert_eq!(x.get(0), Some(2));
+        assert_eq!(x.get(1), Some(5));
+        assert_eq!(x.get(2), Some(8));
+        assert_eq!(x.get(3), None);
+    }
+
+    #[test]
+    fn rget() {
+        let x = StepBy::new(2..10, 3).unwrap();
+        assert_eq!(x.rget(0), Some(8));
+        assert_eq!(x.rget(1), Some(5));
+        assert_eq!(x.rget(2), Some(2));
+        assert_eq!(x.rget(3), None);
+    }
+
+    #[test]
+    fn first() {
+        assert_eq!(StepBy::new(2..9, 3).unwrap().first(), Some(2));
+        assert_eq!(StepBy::new(2..2, 3).unwrap().first(), None);
+    }
+
+    #[test]
+    fn last() {
+        assert_eq!(StepBy::new(2..9, 3).unwrap().last(), Some(8));
+        assert_eq!(StepBy::new(2..8, 3).unwrap().last(), Some(5));
+        assert_eq!(StepBy::new(2..2, 3).unwrap().last(), None);
+    }
+
+    #[test]
+    fn iter() {
+        assert!(StepBy::new(2..9, 3).unwrap().iter().eq([2, 5, 8]));
+        assert!(StepBy::new(2..2, 3).unwrap().iter().eq([]));
+    }
+
+    #[test]
+    fn rev_iter() {
+        assert!(StepBy::new(2..10, 3).unwrap().iter().rev().eq([8, 5, 2]));
+    }
+
+    #[test]
+    fn iter_size_hint() {
+        let x = StepBy::new(2..10, 3).unwrap();
+        let mut iter = x.iter();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        iter.next();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+    }
+
+    #[test]
+    fn get_mut() {
+        let mut x = [2, 3, 4, 5, 6];
+        let mut y = StepBy::new(x.as_mut_sqnc(), 2).unwrap();
+        *y.get_mut(0).unwrap() = 7;
+        *y.get_mut(2).unwrap() = 8;
+        assert!(y.get_mut(3).is_none());
+        assert_eq!(x, [7, 3, 4, 5, 8]);
+    }
+
+    #[test]
+    fn rget_mut() {
+        let mut x = [2, 3, 4, 5];
+        let mut y = StepBy::new(x.as_mut_sqnc(), 2).unwrap();
+        *y.rget_mut(0).unwrap() = 7;
+        *y.rget_mut(1).unwrap() = 8;
+        assert!(y.rget_mut(2).is_none());
+        assert_eq!(x, [8, 3, 7, 5]);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut x = [2, 3, 4, 5, 6];
+        let mut y = StepBy::new(x.as_mut_sqnc(), 2).unwrap();
+        y.iter_mut().for_each(|v| *v += 3);
+        assert_eq!(x, [5, 3, 7, 5, 9]);
+    }
+
+    #[test]
+    fn rev_iter_mut() {
+        let mut x = [2, 3, 4, 5];
+        let mut y = StepBy::new(x.as_mut_sqnc(), 3).unwrap();
+        let mut iter = y.iter_mut().rev();
+        *iter.next().unwrap() = 6;
+        *iter.next().unwrap() = 7;
+        assert!(iter.next().is_none());
+        assert_eq!(x, [7, 3, 4, 6]);
+    }
+
+    #[test]
+    fn map_concat() {
+        let x = Sequence::concat([1, 2, 3], [4, 5, 6]).unwrap().copied();
+        let y = StepBy::new(Sequence::map(x, |v| v * 10), 2).unwrap();
+        assert_eq!(y.get(1), Some(30));
+        assert!(y.iter().eq([10, 30, 50]));
+    }
+}
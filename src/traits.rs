@@ -2,9 +2,18 @@
 //!
 //! See the [crate-level documentation][`crate`].
 
-use crate::{Cloned, Concat, Copied, Map, Repeat, Rev, Select, Wrapper, Zip};
+use crate::sort;
+use crate::{
+    Chunks, ChunksMut, Cloned, Concat, Copied, DebugList, DisplayList, Enumerate, Interleave, Map,
+    Product, Repeat, Rev, Select, Slice, SliceMut, StepBy, Windows, Wrapper, Zip,
+};
+#[cfg(feature = "alloc")]
+use crate::{Flatten, MaskIndices, Unique};
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
 use core::iter::{self, FusedIterator};
-use core::ops::{Deref, DerefMut};
+use core::ops::{Deref, DerefMut, RangeBounds};
 
 // Instead of a generic associated type `Sequence::Item<'a>` we use
 // workaround 3 from [The Better Alternative to Lifetime GATs] for the reasons
@@ -198,6 +207,249 @@ pub trait Sequence: for<'this> SequenceTypes<'this> {
         self.iter().max()
     }
 
+    /// Binary searches this sequence for a given element.
+    ///
+    /// The sequence is assumed to be sorted. If the value is found then
+    /// [`Result::Ok`] is returned, containing the index of the matching
+    /// element. If there are multiple matches, then any one of the matches
+    /// could be returned. If the value is not found then [`Result::Err`] is
+    /// returned, containing the index where a matching element could be
+    /// inserted while maintaining sorted order.
+    ///
+    /// This is the equivalent of [`slice::binary_search()`] and uses only
+    /// [`Sequence::get()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::Sequence;
+    ///
+    /// let x = [1, 3, 3, 5];
+    /// // Using Fully Qualified Syntax to disambiguate from `slice::binary_search()`.
+    /// assert_eq!(Sequence::binary_search(&x, &5), Ok(3));
+    /// assert_eq!(Sequence::binary_search(&x, &4), Err(3));
+    ///
+    /// let y = x.as_sqnc().rev().map(|v| 10 - v);
+    /// assert!(matches!(y.binary_search(&7), Ok(1) | Ok(2)));
+    /// assert_eq!((2..6).binary_search(&9), Err(4));
+    /// ```
+    #[inline]
+    fn binary_search<'a, Q>(&'a self, x: &Q) -> Result<usize, usize>
+    where
+        <Self as SequenceTypes<'a>>::Item: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.binary_search_by(|item| item.borrow().cmp(x))
+    }
+
+    /// Binary searches this sequence with a comparator function.
+    ///
+    /// The comparator function should return an order code that indicates
+    /// whether its argument is `Less`, `Equal` or `Greater` the desired
+    /// target. The sequence is assumed to be sorted accordingly. See
+    /// [`Sequence::binary_search()`] for the meaning of the return value.
+    ///
+    /// This is the equivalent of [`slice::binary_search_by()`] and uses only
+    /// [`Sequence::get()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::Sequence;
+    ///
+    /// // Using Fully Qualified Syntax to disambiguate from `Iterator::map()`.
+    /// let x = Sequence::map(0..10, |v| v * 3);
+    /// assert_eq!(x.binary_search_by(|v| v.cmp(&12)), Ok(4));
+    /// assert_eq!(x.binary_search_by(|v| v.cmp(&13)), Err(5));
+    /// ```
+    fn binary_search_by<'a, F>(&'a self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(<Self as SequenceTypes<'a>>::Item) -> Ordering,
+    {
+        let mut left = 0;
+        let mut right = self.len();
+        while left < right {
+            let mid = left + (right - left) / 2;
+            let Some(item) = self.get(mid) else {
+                break;
+            };
+            match f(item) {
+                Ordering::Less => left = mid + 1,
+                Ordering::Greater => right = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(left)
+    }
+
+    /// Binary searches this sequence with a key extraction function.
+    ///
+    /// The sequence is assumed to be sorted by the key. See
+    /// [`Sequence::binary_search()`] for the meaning of the return value.
+    ///
+    /// This is the equivalent of [`slice::binary_search_by_key()`] and uses
+    /// only [`Sequence::get()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::Sequence;
+    ///
+    /// let x = [(0, 'a'), (2, 'b'), (5, 'c')];
+    /// // Using Fully Qualified Syntax to disambiguate from `slice::binary_search_by_key()`.
+    /// assert_eq!(Sequence::binary_search_by_key(&x, &2, |&(k, _)| k), Ok(1));
+    /// assert_eq!(Sequence::binary_search_by_key(&x, &3, |&(k, _)| k), Err(2));
+    /// ```
+    #[inline]
+    fn binary_search_by_key<'a, B, F>(&'a self, b: &B, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(<Self as SequenceTypes<'a>>::Item) -> B,
+        B: Ord,
+    {
+        self.binary_search_by(|item| f(item).cmp(b))
+    }
+
+    /// Returns the index of the partition point according to the given
+    /// predicate.
+    ///
+    /// The sequence is assumed to be partitioned according to the predicate,
+    /// i.e. all elements for which the predicate returns `true` precede all
+    /// elements for which the predicate returns `false`. The returned index is
+    /// that of the first element for which the predicate returns `false`, or
+    /// the length of the sequence if there is no such element.
+    ///
+    /// This is the equivalent of [`slice::partition_point()`] and uses only
+    /// [`Sequence::get()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::Sequence;
+    ///
+    /// let x = [1, 2, 3, 3, 5, 6, 7];
+    /// let y = x.as_sqnc().select([0, 2, 4, 6].copied()).unwrap();
+    /// assert_eq!(y.partition_point(|&v| v < 5), 2);
+    /// assert_eq!((0..10).partition_point(|v| v * v < 50), 8);
+    /// ```
+    #[inline]
+    fn partition_point<'a, P>(&'a self, mut pred: P) -> usize
+    where
+        P: FnMut(<Self as SequenceTypes<'a>>::Item) -> bool,
+    {
+        self.binary_search_by(|item| {
+            if pred(item) {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        })
+        .unwrap_or_else(|index| index)
+    }
+
+    /// Returns `true` if the elements of this sequence are equal to those of another sequence.
+    ///
+    /// Unlike [`PartialEq`], which adaptors derive by comparing their parts,
+    /// this compares the sequences element by element. The sequences can be of
+    /// different types. Returns `false` without comparing elements if the
+    /// lengths differ. See also [`ElementWise`][`crate::ElementWise`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::Sequence;
+    ///
+    /// let x = [2, 3].copied().concat(4..6).unwrap();
+    /// assert!(x.seq_eq(&(2..6)));
+    /// assert!(!x.seq_eq(&(2..5)));
+    /// ```
+    #[inline]
+    fn seq_eq<'a, 'b, Other>(&'a self, other: &'b Other) -> bool
+    where
+        Other: Sequence + ?Sized,
+        <Self as SequenceTypes<'a>>::Item: PartialEq<<Other as SequenceTypes<'b>>::Item>,
+    {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+
+    /// Compares the elements of this sequence lexicographically with those of another sequence.
+    ///
+    /// This is the equivalent of [`Iterator::cmp()`]. Both sequences must have
+    /// the same element type, which must implement [`Ord`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::Sequence;
+    /// use std::cmp::Ordering;
+    ///
+    /// let x = [2, 3].copied().concat(4..6).unwrap();
+    /// assert_eq!(x.seq_cmp(&(2..6)), Ordering::Equal);
+    /// assert_eq!(x.seq_cmp(&(2..5)), Ordering::Greater);
+    /// assert_eq!(x.seq_cmp(&(3..4)), Ordering::Less);
+    /// ```
+    #[inline]
+    fn seq_cmp<'a, 'b, Other>(&'a self, other: &'b Other) -> Ordering
+    where
+        Other: Sequence + ?Sized,
+        <Self as SequenceTypes<'a>>::Item: Ord,
+        <Other as SequenceTypes<'b>>::Iter: Iterator<Item = <Self as SequenceTypes<'a>>::Item>,
+    {
+        self.iter().cmp(other.iter())
+    }
+
+    /// Compares the elements of this sequence lexicographically with those of another sequence.
+    ///
+    /// This is the equivalent of [`Iterator::partial_cmp()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::Sequence;
+    /// use std::cmp::Ordering;
+    ///
+    /// let x = [1.0, 2.0];
+    /// assert_eq!(x.seq_partial_cmp(&[1.0, 3.0]), Some(Ordering::Less));
+    /// assert_eq!(x.seq_partial_cmp(&[1.0, f64::NAN]), None);
+    /// ```
+    #[inline]
+    fn seq_partial_cmp<'a, 'b, Other>(&'a self, other: &'b Other) -> Option<Ordering>
+    where
+        Other: Sequence + ?Sized,
+        <Self as SequenceTypes<'a>>::Item: PartialOrd<<Other as SequenceTypes<'b>>::Item>,
+    {
+        self.iter().partial_cmp(other.iter())
+    }
+
+    /// Feeds the length and the elements of this sequence into the given [`Hasher`].
+    ///
+    /// Sequences that are equal according to [`Sequence::seq_eq()`] and have
+    /// the same element type produce the same hash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::Sequence;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::Hasher;
+    ///
+    /// let hash = |x: &dyn Fn(&mut DefaultHasher)| {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     x(&mut hasher);
+    ///     hasher.finish()
+    /// };
+    /// let x = [2, 3].copied().concat(4..6).unwrap();
+    /// assert_eq!(hash(&|h| x.seq_hash(h)), hash(&|h| (2..6).seq_hash(h)));
+    /// ```
+    #[inline]
+    fn seq_hash<'a, H>(&'a self, state: &mut H)
+    where
+        H: Hasher,
+        <Self as SequenceTypes<'a>>::Item: Hash,
+    {
+        state.write_usize(self.len());
+        self.iter().for_each(|item| item.hash(state));
+    }
+
     /// Creates a sequence that copies all of its elements.
     ///
     /// # Examples
@@ -316,6 +568,34 @@ pub trait Sequence: for<'this> SequenceTypes<'this> {
         Rev::new(self)
     }
 
+    /// Returns a sequence that steps over `self` by the given amount.
+    ///
+    /// The first element of the returned sequence is the first element of
+    /// `self`, the second element is element `step` of `self`, etc. Unlike
+    /// [`Iterator::step_by()`] the returned sequence supports random access.
+    /// Returns `None` if `step` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::Sequence;
+    ///
+    /// let x = [1, 2, 3, 4, 5];
+    /// let y = x.as_sqnc().sqnc_step_by(2).unwrap();
+    /// assert_eq!(y.len(), 3);
+    /// assert_eq!(y.get(1), Some(&3));
+    /// assert!(y.iter().eq(&[1, 3, 5]));
+    ///
+    /// assert!(x.sqnc_step_by(0).is_none());
+    /// ```
+    #[inline]
+    fn sqnc_step_by(self, step: usize) -> Option<StepBy<Self>>
+    where
+        Self: Sized,
+    {
+        StepBy::new(self, step)
+    }
+
     /// Returns the concatenation with another sequence.
     ///
     /// Returns `None` if the length of the concatenation exceeds [`usize::MAX`].
@@ -339,6 +619,77 @@ pub trait Sequence: for<'this> SequenceTypes<'this> {
         Concat::new(self, other)
     }
 
+    /// Returns a sequence that alternates between the elements of `self` and `other`.
+    ///
+    /// The elements of the returned sequence are `[a0, b0, a1, b1, ...]`, where
+    /// `a` is `self` and `b` is `other`. Returns `None` if the sequences have
+    /// different lengths or if the length of the returned sequence exceeds
+    /// [`usize::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::{Sequence, MutSequence};
+    ///
+    /// let mut re = [1, 3];
+    /// let mut im = [2, 4];
+    /// let mut z = re.as_mut_sqnc().interleave(im.as_mut_sqnc()).unwrap();
+    /// assert!(z.iter().copied().eq(1..5));
+    /// *z.get_mut(3).unwrap() = 5;
+    /// assert_eq!(im, [2, 5]);
+    ///
+    /// assert!([0, 1].interleave([2]).is_none());
+    /// ```
+    #[inline]
+    fn interleave<Other>(self, other: Other) -> Option<Interleave<Self, Other>>
+    where
+        Self: Sized,
+        Other: Sequence + for<'a> SequenceTypes<'a, Item = <Self as SequenceTypes<'a>>::Item>,
+    {
+        Interleave::new(self, other)
+    }
+
+    /// Returns the concatenation of all sequences in `self`.
+    ///
+    /// This adaptor applies to sequences of sequences that can be converted
+    /// into an iterator, e.g. the chunks returned by
+    /// [`Sequence::sqnc_chunks()`], the windows returned by
+    /// [`Sequence::sqnc_windows()`], the rows of a [`crate::jagged()`]
+    /// sequence or a sequence mapped to [`crate::Arange`]s. Returns `None` if
+    /// the total length exceeds [`usize::MAX`].
+    ///
+    /// The offsets of the inner sequences are stored upon construction, such
+    /// that [`Sequence::get()`] performs a binary search. Requires feature
+    /// `alloc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::{Arange, Sequence, MutSequence};
+    ///
+    /// let x = [0, 1, 2, 3];
+    /// let y = x.sqnc_windows(2).unwrap().flatten().unwrap();
+    /// assert_eq!(y.len(), 6);
+    /// assert_eq!(y.get(2), Some(&1));
+    /// assert!(y.iter().eq(&[0, 1, 1, 2, 2, 3]));
+    ///
+    /// let z = [2usize, 0, 3].copied().map(|n| Arange::from_range(0..n).unwrap());
+    /// assert!(z.flatten().unwrap().iter().eq([0, 1, 0, 1, 2]));
+    ///
+    /// let mut w = sqnc::jagged([0, 2, 3].copied(), [0, 1, 2]).unwrap().flatten().unwrap();
+    /// *w.get_mut(2).unwrap() = 4;
+    /// assert!(w.iter().eq(&[0, 1, 4]));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn flatten(self) -> Option<Flatten<Self>>
+    where
+        Self: Sized,
+        for<'a> <Self as SequenceTypes<'a>>::Item: Sequence + IntoIterator,
+    {
+        Flatten::new(self)
+    }
+
     /// Returns a selection of the sequence or `None` if any index is out of bounds.
     ///
     /// The sequence of indices must have [`usize`] as element type.
@@ -366,171 +717,858 @@ pub trait Sequence: for<'this> SequenceTypes<'this> {
         Select::new(self, indices)
     }
 
-    /// 'Zips up' two sequences into a single sequence of pairs.
+    /// Returns a selection with distinct indices or `None` if any index is out of bounds or occurs more than once.
     ///
-    /// Returns `None` if the sequences have different lengths.
+    /// Unlike [`Sequence::select()`], the indices are checked to be distinct,
+    /// such that the selection of a [`MutSequence`] is a [`MutSequence`] as
+    /// well. See [`Unique`] for details. Requires feature `alloc`.
     ///
     /// # Examples
     ///
     /// ```
-    /// use sqnc::Sequence;
+    /// use sqnc::{MutSequence, Sequence};
     ///
-    /// let x = 0..3;
-    /// let y = 3..6;
-    /// let z = Sequence::zip(x, y).unwrap();
-    /// assert_eq!(z.get(1), Some((1, 4)));
+    /// let mut x = *b"abcde";
+    /// let mut y = x.as_mut_sqnc().select_unique(vec![4, 0, 2]).unwrap();
+    /// y.iter_mut().for_each(|c| c.make_ascii_uppercase());
+    /// assert_eq!(&x, b"AbCdE");
+    ///
+    /// assert!(x.select_unique(vec![4, 0, 4]).is_none()); // Index `4` occurs twice.
+    /// assert!(x.select_unique(vec![4, 0, 5]).is_none()); // Index `5` is out of bounds.
     /// ```
+    #[cfg(feature = "alloc")]
     #[inline]
-    fn zip<Other>(self, other: Other) -> Option<Zip<Self, Other>>
+    fn select_unique<I>(self, indices: I) -> Option<Select<Self, Unique>>
     where
         Self: Sized,
-        Other: Sequence,
+        I: IntoIterator<Item = usize>,
     {
-        Zip::new(self, other)
+        Select::new(self, Unique::new(indices)?)
     }
 
-    /// Returns a [`Sequence`] that references `self`.
+    /// Returns a selection of the elements for which `mask` is `true` or `None` if the lengths differ.
     ///
-    /// This is useful to allow applying sequence adaptors while still
-    /// retaining ownership of the original sequence.
+    /// The mask must have [`bool`] as element type. The indices of the `true`
+    /// elements are collected once, upon construction, after which the
+    /// selection provides random access. The indices are distinct, such that
+    /// the selection of a [`MutSequence`] is a [`MutSequence`] as well.
+    /// Requires feature `alloc`.
     ///
-    /// This is the immutable counterpart of [`MutSequence::as_mut_sqnc()`].
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::{MutSequence, Sequence};
+    ///
+    /// let x = *b"abcde";
+    /// let y = x.compress([true, false, false, true, true].copied()).unwrap();
+    /// assert!(y.iter().eq(b"ade"));
+    ///
+    /// assert!(x.compress([true, false].copied()).is_none());
+    /// ```
+    ///
+    /// Modifying the elements that satisfy a predicate:
+    ///
+    /// ```
+    /// use sqnc::{MutSequence, Sequence};
+    ///
+    /// let mut x = [3, -1, 4, -1, 5];
+    /// let mask = Sequence::map(x, |v| *v < 0);
+    /// x.as_mut_sqnc().compress(mask).unwrap().sqnc_fill(0);
+    /// assert_eq!(x, [3, 0, 4, 0, 5]);
+    /// ```
+    ///
+    /// There is deliberately no lazy counterpart of this adaptor: without
+    /// stored indices a selection by mask cannot provide random access. If
+    /// feature `alloc` is not available, the masked elements can be visited
+    /// by zipping the iterators of the sequence and the mask:
+    ///
+    /// ```
+    /// let mut x = [3, -1, 4, -1, 5];
+    /// let mask = [false, true, false, true, false];
+    /// x.iter_mut()
+    ///     .zip(mask.iter())
+    ///     .filter_map(|(v, m)| m.then_some(v))
+    ///     .for_each(|v| *v = 0);
+    /// assert_eq!(x, [3, 0, 4, 0, 5]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn compress<Mask>(self, mask: Mask) -> Option<Select<Self, MaskIndices>>
+    where
+        Self: Sized,
+        Mask: Sequence + for<'a> SequenceTypes<'a, Item = bool>,
+    {
+        if mask.len() != self.len() {
+            return None;
+        }
+        Select::new(self, MaskIndices::new(&mask))
+    }
+
+    /// Returns a contiguous subsequence or `None` if the range is out of bounds.
+    ///
+    /// Unlike selecting a [`core::ops::Range`] of indices using
+    /// [`Sequence::select()`], the subsequence maps indices directly to the
+    /// parent sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::{Sequence, MutSequence};
+    ///
+    /// let x = [0, 1, 2, 3, 4];
+    /// let y = x.sqnc_slice(1..3).unwrap();
+    /// assert!(y.iter().eq(&[1, 2]));
+    /// assert!(x.sqnc_slice(3..).unwrap().iter().eq(&[3, 4]));
+    /// assert!(x.sqnc_slice(..=5).is_none()); // Index `5` is out of bounds.
+    ///
+    /// let mut z = [0, 1, 2, 3, 4];
+    /// z.as_mut_sqnc().sqnc_slice(..2).unwrap().assign([5, 6]).unwrap();
+    /// assert_eq!(z, [5, 6, 2, 3, 4]);
+    /// ```
+    #[inline]
+    fn sqnc_slice<R>(self, range: R) -> Option<Slice<Self>>
+    where
+        Self: Sized,
+        R: RangeBounds<usize>,
+    {
+        Slice::new(self, range)
+    }
+
+    /// Divides the sequence into two subsequences at an index.
+    ///
+    /// The first subsequence contains all indices from `[0, mid)` and the
+    /// second all indices from `[mid, len)`. Returns `None` if `mid` is larger
+    /// than the length of the sequence.
+    ///
+    /// Both subsequences hold a clone of `self`. Use [`Sequence::as_sqnc()`] to
+    /// split a reference to a sequence and [`MutSequence::sqnc_split_at_mut()`] to
+    /// obtain two mutable subsequences.
     ///
     /// # Examples
     ///
     /// ```
     /// use sqnc::Sequence;
     ///
-    /// let mut x = vec![0, 1, 2, 3];
-    /// let mut y = x.as_sqnc().copied();
-    /// assert_eq!(y.get(0), Some(0));
-    /// assert_eq!(x.get(0), Some(&0));
+    /// let x = [0, 1, 2, 3, 4];
+    /// let (y, z) = x.as_sqnc().sqnc_split_at(2).unwrap();
+    /// assert!(y.iter().eq(&[0, 1]));
+    /// assert!(z.iter().eq(&[2, 3, 4]));
+    ///
+    /// assert!(x.as_sqnc().sqnc_split_at(6).is_none());
     /// ```
-    fn as_sqnc(&self) -> Wrapper<&'_ Self, ((),)> {
-        self.into()
+    #[inline]
+    fn sqnc_split_at(self, mid: usize) -> Option<(Slice<Self>, Slice<Self>)>
+    where
+        Self: Sized + Clone,
+    {
+        Some((self.clone().sqnc_slice(..mid)?, self.sqnc_slice(mid..)?))
     }
-}
 
-/// An interface for mutable sequences.
-///
-/// This is the mutable extension of [`Sequence`]. Associated types with
-/// generic lifetimes are defined in the [`SequenceTypes`] trait.
-///
-/// See the [crate-level documentation][`crate`] for more information.
-pub trait MutSequence: Sequence + for<'this> MutSequenceTypes<'this> {
-    /// Returns a mutable reference to the element at the given index or `None`.
+    /// Returns the cartesian product with another sequence.
+    ///
+    /// Element `i` of the returned sequence is the pair of element `i / n` of
+    /// `self` and element `i % n` of `other`, where `n` is the length of
+    /// `other`. Returns `None` if the length of the product overflows.
+    ///
+    /// See [`crate::multiproduct()`] for the product of more than two
+    /// sequences.
     ///
     /// # Examples
     ///
     /// ```
-    /// use sqnc::MutSequence;
+    /// use sqnc::Sequence;
     ///
-    /// let mut x = [1, 2, 3];
-    /// if let Some(elem) = MutSequence::get_mut(&mut x, 1) {
-    ///     *elem = 4;
-    /// }
-    /// assert_eq!(x, [1, 4, 3]);
+    /// let x = 0..2;
+    /// let y = *b"abc";
+    /// let z = x.sqnc_product(y).unwrap();
+    /// assert_eq!(z.len(), 6);
+    /// assert_eq!(z.get(1), Some((0, &b'b')));
+    /// assert_eq!(z.get(4), Some((1, &b'b')));
+    ///
+    /// assert!((0..usize::MAX).sqnc_product(0..2).is_none());
     /// ```
-    fn get_mut(&mut self, index: usize) -> Option<<Self as MutSequenceTypes<'_>>::MutItem>;
+    #[inline]
+    fn sqnc_product<Other>(self, other: Other) -> Option<Product<Self, Other>>
+    where
+        Self: Sized,
+        Other: Sequence,
+    {
+        Product::new(self, other)
+    }
 
-    /// Returns a mutable reference to the element at the given index counting from the end or `None`.
+    /// Returns a sequence of pairs of indices and elements of `self`.
+    ///
+    /// Element `i` of the returned sequence is `(i, self.get(i).unwrap())`.
+    /// Unlike [`Sequence::zip()`] with a range, the length of the sequence does
+    /// not have to be checked.
     ///
     /// # Examples
     ///
     /// ```
-    /// use sqnc::MutSequence;
+    /// use sqnc::{Sequence, MutSequence};
     ///
-    /// let mut x = [1, 2, 3];
-    /// if let Some(elem) = MutSequence::rget_mut(&mut x, 0) {
-    ///     *elem = 4;
-    /// }
+    /// let x = *b"abc";
+    /// let y = x.as_sqnc().sqnc_enumerate();
+    /// assert_eq!(y.get(1), Some((1, &b'b')));
+    /// assert!(y.iter().eq([(0, &b'a'), (1, &b'b'), (2, &b'c')]));
+    ///
+    /// let mut z = [1, 1, 1];
+    /// z.as_mut_sqnc().sqnc_enumerate().iter_mut().for_each(|(i, v)| *v += i);
+    /// assert_eq!(z, [1, 2, 3]);
+    /// ```
+    #[inline]
+    fn sqnc_enumerate(self) -> Enumerate<Self>
+    where
+        Self: Sized,
+    {
+        Enumerate::new(self)
+    }
+
+    /// 'Zips up' two sequences into a single sequence of pairs.
+    ///
+    /// Returns `None` if the sequences have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::Sequence;
+    ///
+    /// let x = 0..3;
+    /// let y = 3..6;
+    /// let z = Sequence::zip(x, y).unwrap();
+    /// assert_eq!(z.get(1), Some((1, 4)));
+    /// ```
+    #[inline]
+    fn zip<Other>(self, other: Other) -> Option<Zip<Self, Other>>
+    where
+        Self: Sized,
+        Other: Sequence,
+    {
+        Zip::new(self, other)
+    }
+
+    /// Returns a sequence of overlapping windows of length `size`.
+    ///
+    /// The windows are sequences that reference `self`. If `self` is shorter
+    /// than `size`, the sequence of windows is empty. Returns `None` if `size`
+    /// is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::Sequence;
+    ///
+    /// let x = [1, 4, 9, 16];
+    /// let y = x.sqnc_windows(2).unwrap();
+    /// assert_eq!(y.len(), 3);
+    /// assert!(y.get(1).unwrap().iter().eq(&[4, 9]));
+    ///
+    /// // Finite differences.
+    /// let dx = Sequence::map(y, |w| w.get(1).unwrap() - w.get(0).unwrap());
+    /// assert!(dx.iter().eq([3, 5, 7]));
+    ///
+    /// assert!(x.sqnc_windows(0).is_none());
+    /// ```
+    #[inline]
+    fn sqnc_windows(self, size: usize) -> Option<Windows<Self>>
+    where
+        Self: Sized,
+    {
+        Windows::new(self, size)
+    }
+
+    /// Returns a sequence of non-overlapping chunks of length `size`.
+    ///
+    /// The chunks are sequences that reference `self`. If the length of
+    /// `self` is not a multiple of `size`, the last chunk is shorter than
+    /// `size`. Returns `None` if `size` is zero.
+    ///
+    /// See [`MutSequence::sqnc_chunks_mut()`] for mutable chunks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::Sequence;
+    ///
+    /// let x = [0, 1, 2, 3, 4];
+    /// let y = x.sqnc_chunks(2).unwrap();
+    /// assert_eq!(y.len(), 3);
+    /// assert!(y.get(1).unwrap().iter().eq(&[2, 3]));
+    /// assert!(y.get(2).unwrap().iter().eq(&[4]));
+    ///
+    /// assert!(x.sqnc_chunks(0).is_none());
+    /// ```
+    #[inline]
+    fn sqnc_chunks(self, size: usize) -> Option<Chunks<Self>>
+    where
+        Self: Sized,
+    {
+        Chunks::new(self, size)
+    }
+
+    /// Returns a sequence of non-overlapping chunks of exactly length `size`.
+    ///
+    /// Like [`Sequence::sqnc_chunks()`], except that the trailing elements
+    /// that do not fill a whole chunk are omitted. Returns `None` if `size` is
+    /// zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::Sequence;
+    ///
+    /// let x = [0, 1, 2, 3, 4];
+    /// let y = x.sqnc_chunks_exact(2).unwrap();
+    /// assert_eq!(y.len(), 2);
+    /// assert!(y.get(1).unwrap().iter().eq(&[2, 3]));
+    /// assert!(y.get(2).is_none());
+    /// ```
+    #[inline]
+    fn sqnc_chunks_exact(self, size: usize) -> Option<Chunks<Self>>
+    where
+        Self: Sized,
+    {
+        Chunks::new_exact(self, size)
+    }
+
+    /// Returns a [`Sequence`] that references `self`.
+    ///
+    /// This is useful to allow applying sequence adaptors while still
+    /// retaining ownership of the original sequence.
+    ///
+    /// This is the immutable counterpart of [`MutSequence::as_mut_sqnc()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::Sequence;
+    ///
+    /// let mut x = vec![0, 1, 2, 3];
+    /// let mut y = x.as_sqnc().copied();
+    /// assert_eq!(y.get(0), Some(0));
+    /// assert_eq!(x.get(0), Some(&0));
+    /// ```
+    fn as_sqnc(&self) -> Wrapper<&'_ Self, ((),)> {
+        self.into()
+    }
+
+    /// Returns a wrapper that formats the elements like a slice using [`Debug`][`core::fmt::Debug`].
+    ///
+    /// The adaptors in this crate derive [`Debug`][`core::fmt::Debug`], which
+    /// shows their internal structure. The returned wrapper shows the
+    /// elements instead. Long sequences can be abbreviated using
+    /// [`DebugList::truncate()`][`crate::DebugList::truncate()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::Sequence;
+    ///
+    /// let x = *b"abcd";
+    /// let y = Sequence::map(x.select([3, 1, 2].copied()).unwrap(), |c| *c as char);
+    /// assert_eq!(format!("{:?}", y.debug_list()), "['d', 'b', 'c']");
+    /// assert_eq!(format!("{:?}", y.debug_list().truncate(2)), "['d', ..., 'c']");
+    /// ```
+    #[inline]
+    fn debug_list(&self) -> DebugList<'_, Self> {
+        DebugList::new(self)
+    }
+
+    /// Returns a wrapper that formats the elements like a slice using [`Display`][`core::fmt::Display`].
+    ///
+    /// Formatting options, e.g. the precision, are applied to every element.
+    /// Long sequences can be abbreviated using
+    /// [`DisplayList::truncate()`][`crate::DisplayList::truncate()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::Sequence;
+    ///
+    /// let x = sqnc::Linspace::new(0.0, 1.0, 5).unwrap();
+    /// assert_eq!(format!("{:.2}", x.display()), "[0.00, 0.25, 0.50, 0.75, 1.00]");
+    /// assert_eq!(format!("{}", x.display().truncate(2)), "[0, ..., 1]");
+    /// ```
+    #[inline]
+    fn display(&self) -> DisplayList<'_, Self> {
+        DisplayList::new(self)
+    }
+}
+
+/// An interface for mutable sequences.
+///
+/// This is the mutable extension of [`Sequence`]. Associated types with
+/// generic lifetimes are defined in the [`SequenceTypes`] trait.
+///
+/// See the [crate-level documentation][`crate`] for more information.
+pub trait MutSequence: Sequence + for<'this> MutSequenceTypes<'this> {
+    /// Returns a mutable reference to the element at the given index or `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::MutSequence;
+    ///
+    /// let mut x = [1, 2, 3];
+    /// if let Some(elem) = MutSequence::get_mut(&mut x, 1) {
+    ///     *elem = 4;
+    /// }
+    /// assert_eq!(x, [1, 4, 3]);
+    /// ```
+    fn get_mut(&mut self, index: usize) -> Option<<Self as MutSequenceTypes<'_>>::MutItem>;
+
+    /// Returns a mutable reference to the element at the given index counting from the end or `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::MutSequence;
+    ///
+    /// let mut x = [1, 2, 3];
+    /// if let Some(elem) = MutSequence::rget_mut(&mut x, 0) {
+    ///     *elem = 4;
+    /// }
     /// assert_eq!(x, [1, 2, 4]);
     /// ```
     #[inline]
-    fn rget_mut(&mut self, rindex: usize) -> Option<<Self as MutSequenceTypes<'_>>::MutItem> {
-        self.get_mut(self.len().checked_sub(rindex + 1)?)
+    fn rget_mut(&mut self, rindex: usize) -> Option<<Self as MutSequenceTypes<'_>>::MutItem> {
+        self.get_mut(self.len().checked_sub(rindex + 1)?)
+    }
+
+    /// Returns a mutable reference to the first element or `None` if the sequence is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::MutSequence;
+    ///
+    /// let mut x = [1, 2, 3];
+    /// if let Some(elem) = MutSequence::first_mut(&mut x) {
+    ///     *elem = 4;
+    /// }
+    /// assert_eq!(x, [4, 2, 3]);
+    /// ```
+    #[inline]
+    fn first_mut(&mut self) -> Option<<Self as MutSequenceTypes<'_>>::MutItem> {
+        self.get_mut(0)
+    }
+
+    /// Returns a mutable reference to the last element or `None` if the sequence is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::MutSequence;
+    ///
+    /// let mut x = [1, 2, 3];
+    /// if let Some(elem) = MutSequence::last_mut(&mut x) {
+    ///     *elem = 4;
+    /// }
+    /// assert_eq!(x, [1, 2, 4]);
+    /// ```
+    #[inline]
+    fn last_mut(&mut self) -> Option<<Self as MutSequenceTypes<'_>>::MutItem> {
+        self.get_mut(self.len().checked_sub(1)?)
+    }
+
+    /// Returns an iterator that returns mutable references to elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::MutSequence;
+    ///
+    /// let mut x = [1, 2, 3];
+    /// MutSequence::iter_mut(&mut x).for_each(|elem| *elem += 3);
+    /// assert_eq!(x, [4, 5, 6]);
+    /// ```
+    fn iter_mut(&mut self) -> <Self as MutSequenceTypes<'_>>::IterMut;
+
+    /// Assigns every element of this sequence.
+    ///
+    /// Returns `None` and assigns nothing if the length of the source (using
+    /// [`ExactSizeIterator::len()`]) differs from the length of the target.
+    /// However, if the source iterator returns fewer elements than announced,
+    /// those elements will be assigned to the target and this function does
+    /// return `Some(())`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::MutSequence;
+    ///
+    /// let mut x = [1, 2, 3];
+    /// MutSequence::assign(&mut x, 4..7).unwrap();
+    /// assert_eq!(x, [4, 5, 6]);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn assign<Other, Item>(&mut self, other: Other) -> Option<()>
+    where
+        Self: for<'a> MutSequenceTypes<'a, MutItem = &'a mut Item>,
+        Other: IntoIterator<Item = Item>,
+        Other::IntoIter: ExactSizeIterator,
+    {
+        let other = other.into_iter();
+        if self.len() != other.len() {
+            return None;
+        }
+        iter::zip(self.iter_mut(), other).for_each(|(s, o)| *s = o);
+        Some(())
+    }
+
+    /// Swaps two elements of the sequence.
+    ///
+    /// Returns `None` and swaps nothing if any of the indices is out of
+    /// bounds.
+    ///
+    /// This is the equivalent of [`slice::swap()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::{Sequence, MutSequence};
+    ///
+    /// let mut x = [0, 1, 2, 3];
+    /// x.as_mut_sqnc().rev().sqnc_swap(0, 2).unwrap();
+    /// assert_eq!(x, [0, 3, 2, 1]);
+    /// assert!(x.as_mut_sqnc().sqnc_swap(0, 4).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    fn sqnc_swap<T>(&mut self, a: usize, b: usize) -> Option<()>
+    where
+        Self: UniqueMutSequence + for<'a> MutSequenceTypes<'a, MutItem = &'a mut T>,
+    {
+        sort::swap(self, a, b)
+    }
+
+    /// Reverses the order of the elements of the sequence in place.
+    ///
+    /// This is the equivalent of [`slice::reverse()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::{Sequence, MutSequence};
+    ///
+    /// let mut x = [0, 1, 2, 3, 4];
+    /// x.as_mut_sqnc().sqnc_slice(1..).unwrap().sqnc_reverse();
+    /// assert_eq!(x, [0, 4, 3, 2, 1]);
+    /// ```
+    #[inline]
+    fn sqnc_reverse<T>(&mut self)
+    where
+        Self: UniqueMutSequence + for<'a> MutSequenceTypes<'a, MutItem = &'a mut T>,
+    {
+        let len = self.len();
+        sort::reverse_range(self, 0, len);
+    }
+
+    /// Rotates the sequence in place such that the element at index `mid`
+    /// becomes the first element.
+    ///
+    /// Returns `None` and rotates nothing if `mid` is larger than the length
+    /// of the sequence.
+    ///
+    /// This is the equivalent of [`slice::rotate_left()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::{Sequence, MutSequence};
+    ///
+    /// let mut x = [0, 1, 2, 3, 4];
+    /// x.as_mut_sqnc().rev().sqnc_rotate_left(2).unwrap();
+    /// assert_eq!(x, [3, 4, 0, 1, 2]);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn sqnc_rotate_left<T>(&mut self, mid: usize) -> Option<()>
+    where
+        Self: UniqueMutSequence + for<'a> MutSequenceTypes<'a, MutItem = &'a mut T>,
+    {
+        sort::rotate_left(self, mid)
+    }
+
+    /// Rotates the sequence in place such that the element at index `len - k`
+    /// becomes the first element.
+    ///
+    /// Returns `None` and rotates nothing if `k` is larger than the length of
+    /// the sequence.
+    ///
+    /// This is the equivalent of [`slice::rotate_right()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::{Sequence, MutSequence};
+    ///
+    /// let mut x = [0, 1, 2, 3, 4];
+    /// x.as_mut_sqnc().sqnc_rotate_right(2).unwrap();
+    /// assert_eq!(x, [3, 4, 0, 1, 2]);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn sqnc_rotate_right<T>(&mut self, k: usize) -> Option<()>
+    where
+        Self: UniqueMutSequence + for<'a> MutSequenceTypes<'a, MutItem = &'a mut T>,
+    {
+        let mid = self.len().checked_sub(k)?;
+        sort::rotate_left(self, mid)
+    }
+
+    /// Fills the sequence with clones of `value`.
+    ///
+    /// This is the equivalent of [`slice::fill()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::{Sequence, MutSequence};
+    ///
+    /// let mut x = [0, 1, 2, 3, 4];
+    /// x.as_mut_sqnc().select(sqnc::Arange::new(1, 2, 2).unwrap()).unwrap().sqnc_fill(5);
+    /// assert_eq!(x, [0, 5, 2, 5, 4]);
+    /// ```
+    #[inline]
+    fn sqnc_fill<T>(&mut self, value: T)
+    where
+        Self: for<'a> MutSequenceTypes<'a, MutItem = &'a mut T>,
+        T: Clone,
+    {
+        self.iter_mut().for_each(|elem| *elem = value.clone());
+    }
+
+    /// Sorts the sequence in place.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements), in-place
+    /// (i.e., does not allocate), and *O*(*n* \* log(*n*)) worst-case. The
+    /// elements are reordered by swapping via [`MutSequence::get_mut()`],
+    /// which is why `Self` must implement [`UniqueMutSequence`].
+    ///
+    /// This is the equivalent of [`slice::sort_unstable()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::{Sequence, MutSequence};
+    ///
+    /// let mut x = [5, 4, 3, 2, 1, 0];
+    /// x.as_mut_sqnc().sqnc_step_by(2).unwrap().sqnc_sort_unstable();
+    /// assert_eq!(x, [1, 4, 3, 2, 5, 0]);
+    /// ```
+    #[inline]
+    fn sqnc_sort_unstable<T>(&mut self)
+    where
+        Self: UniqueMutSequence + for<'a> MutSequenceTypes<'a, MutItem = &'a mut T>,
+        T: Ord,
+    {
+        sort::heapsort(self, T::lt);
+    }
+
+    /// Sorts the sequence in place with a comparator function.
+    ///
+    /// See [`MutSequence::sqnc_sort_unstable()`] for details.
+    ///
+    /// This is the equivalent of [`slice::sort_unstable_by()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::{Sequence, MutSequence};
+    ///
+    /// let mut x = [1, 2, 0, 3];
+    /// x.as_mut_sqnc().rev().sqnc_sort_unstable_by(|a, b| b.cmp(a));
+    /// assert_eq!(x, [0, 1, 2, 3]);
+    /// ```
+    #[inline]
+    fn sqnc_sort_unstable_by<T, F>(&mut self, mut compare: F)
+    where
+        Self: UniqueMutSequence + for<'a> MutSequenceTypes<'a, MutItem = &'a mut T>,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        sort::heapsort(self, |a, b| compare(a, b) == Ordering::Less);
+    }
+
+    /// Sorts the sequence in place with a key extraction function.
+    ///
+    /// See [`MutSequence::sqnc_sort_unstable()`] for details.
+    ///
+    /// This is the equivalent of [`slice::sort_unstable_by_key()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::{Sequence, MutSequence};
+    ///
+    /// let mut x = [-5i32, 4, 1, -3, 2];
+    /// x.as_mut_sqnc().sqnc_sort_unstable_by_key(|v| v.abs());
+    /// assert_eq!(x, [1, 2, -3, 4, -5]);
+    /// ```
+    #[inline]
+    fn sqnc_sort_unstable_by_key<T, K, F>(&mut self, mut f: F)
+    where
+        Self: UniqueMutSequence + for<'a> MutSequenceTypes<'a, MutItem = &'a mut T>,
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        sort::heapsort(self, |a, b| f(a).lt(&f(b)));
+    }
+
+    /// Sorts the sequence in place, preserving the order of equal elements.
+    ///
+    /// This sort allocates two vectors of the length of the sequence: one
+    /// holding mutable references to all elements and one holding the
+    /// permutation that sorts the sequence. The elements are then moved into
+    /// place by swapping. Requires feature `alloc`.
+    ///
+    /// This is the equivalent of [`slice::sort()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqnc::{Sequence, MutSequence};
+    ///
+    /// let mut x = [1, 3, 0, 2];
+    /// x.as_mut_sqnc().rev().sqnc_sort();
+    /// assert_eq!(x, [3, 2, 1, 0]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn sqnc_sort<T>(&mut self)
+    where
+        Self: for<'a> MutSequenceTypes<'a, MutItem = &'a mut T>,
+        T: Ord,
+    {
+        sort::stable_sort(self, T::lt);
     }
 
-    /// Returns a mutable reference to the first element or `None` if the sequence is empty.
+    /// Sorts the sequence in place with a comparator function, preserving the
+    /// order of equal elements.
+    ///
+    /// See [`MutSequence::sqnc_sort()`] for details. Requires feature `alloc`.
+    ///
+    /// This is the equivalent of [`slice::sort_by()`].
     ///
     /// # Examples
     ///
     /// ```
-    /// use sqnc::MutSequence;
+    /// use sqnc::{Sequence, MutSequence};
     ///
-    /// let mut x = [1, 2, 3];
-    /// if let Some(elem) = MutSequence::first_mut(&mut x) {
-    ///     *elem = 4;
-    /// }
-    /// assert_eq!(x, [4, 2, 3]);
+    /// let mut x = [(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')];
+    /// x.as_mut_sqnc().sqnc_sort_by(|a, b| a.0.cmp(&b.0));
+    /// assert_eq!(x, [(0, 'b'), (0, 'd'), (1, 'a'), (1, 'c')]);
     /// ```
+    #[cfg(feature = "alloc")]
     #[inline]
-    fn first_mut(&mut self) -> Option<<Self as MutSequenceTypes<'_>>::MutItem> {
-        self.get_mut(0)
+    fn sqnc_sort_by<T, F>(&mut self, mut compare: F)
+    where
+        Self: for<'a> MutSequenceTypes<'a, MutItem = &'a mut T>,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        sort::stable_sort(self, |a, b| compare(a, b) == Ordering::Less);
     }
 
-    /// Returns a mutable reference to the last element or `None` if the sequence is empty.
+    /// Sorts the sequence in place with a key extraction function, preserving
+    /// the order of equal elements.
+    ///
+    /// See [`MutSequence::sqnc_sort()`] for details. Requires feature `alloc`.
+    ///
+    /// This is the equivalent of [`slice::sort_by_key()`].
     ///
     /// # Examples
     ///
     /// ```
-    /// use sqnc::MutSequence;
+    /// use sqnc::{Sequence, MutSequence};
     ///
-    /// let mut x = [1, 2, 3];
-    /// if let Some(elem) = MutSequence::last_mut(&mut x) {
-    ///     *elem = 4;
-    /// }
-    /// assert_eq!(x, [1, 2, 4]);
+    /// let mut x = [(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')];
+    /// x.as_mut_sqnc().sqnc_sort_by_key(|v| v.0);
+    /// assert_eq!(x, [(0, 'b'), (0, 'd'), (1, 'a'), (1, 'c')]);
     /// ```
+    #[cfg(feature = "alloc")]
     #[inline]
-    fn last_mut(&mut self) -> Option<<Self as MutSequenceTypes<'_>>::MutItem> {
-        self.get_mut(self.len().checked_sub(1)?)
+    fn sqnc_sort_by_key<T, K, F>(&mut self, mut f: F)
+    where
+        Self: for<'a> MutSequenceTypes<'a, MutItem = &'a mut T>,
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        sort::stable_sort(self, |a, b| f(a).lt(&f(b)));
     }
 
-    /// Returns an iterator that returns mutable references to elements.
+    /// Returns an iterator over non-overlapping mutable chunks of length `size`.
+    ///
+    /// The chunks are mutable sequences that reference `self`. If the length
+    /// of `self` is not a multiple of `size`, the last chunk is shorter than
+    /// `size`. Returns `None` if `size` is zero.
+    ///
+    /// Since `self` implements [`UniqueMutSequence`], the elements of
+    /// different chunks don't alias and the chunks can be mutated
+    /// independently.
+    ///
+    /// This is the mutable counterpart of [`Sequence::sqnc_chunks()`].
     ///
     /// # Examples
     ///
     /// ```
-    /// use sqnc::MutSequence;
+    /// use sqnc::{Sequence, MutSequence};
     ///
-    /// let mut x = [1, 2, 3];
-    /// MutSequence::iter_mut(&mut x).for_each(|elem| *elem += 3);
-    /// assert_eq!(x, [4, 5, 6]);
+    /// let mut x = [0, 1, 2, 3, 4];
+    /// let mut y = x.as_mut_sqnc().rev();
+    /// for (i, mut chunk) in y.sqnc_chunks_mut(2).unwrap().enumerate() {
+    ///     chunk.iter_mut().for_each(|v| *v = i);
+    /// }
+    /// assert_eq!(x, [2, 1, 1, 0, 0]);
+    ///
+    /// assert!(x.sqnc_chunks_mut(0).is_none());
     /// ```
-    fn iter_mut(&mut self) -> <Self as MutSequenceTypes<'_>>::IterMut;
+    #[inline]
+    fn sqnc_chunks_mut(&mut self, size: usize) -> Option<ChunksMut<'_, Self>>
+    where
+        Self: UniqueMutSequence,
+    {
+        ChunksMut::new(self, size)
+    }
 
-    /// Assigns every element of this sequence.
+    /// Divides the sequence into two mutable subsequences at an index.
     ///
-    /// Returns `None` and assigns nothing if the length of the source (using
-    /// [`ExactSizeIterator::len()`]) differs from the length of the target.
-    /// However, if the source iterator returns fewer elements than announced,
-    /// those elements will be assigned to the target and this function does
-    /// return `Some(())`.
+    /// The first subsequence contains all indices from `[0, mid)` and the
+    /// second all indices from `[mid, len)`. Returns `None` if `mid` is larger
+    /// than the length of the sequence.
+    ///
+    /// Both subsequences borrow `self` mutably. Since `Self` implements
+    /// [`UniqueMutSequence`] and the subsequences cover disjoint indices, they
+    /// can be mutated independently.
+    ///
+    /// This is the mutable counterpart of [`Sequence::sqnc_split_at()`].
     ///
     /// # Examples
     ///
     /// ```
-    /// use sqnc::MutSequence;
+    /// use sqnc::{Sequence, MutSequence};
     ///
-    /// let mut x = [1, 2, 3];
-    /// MutSequence::assign(&mut x, 4..7).unwrap();
-    /// assert_eq!(x, [4, 5, 6]);
+    /// let mut x = [0, 1, 2];
+    /// let mut y = x.as_mut_sqnc().rev();
+    /// let (mut a, mut b) = y.sqnc_split_at_mut(1).unwrap();
+    /// a.iter_mut().zip(b.iter_mut()).for_each(|(a, b)| *a += *b);
+    /// assert_eq!(x, [0, 1, 3]);
     /// ```
     #[inline]
-    #[must_use]
-    fn assign<Other, Item>(&mut self, other: Other) -> Option<()>
+    fn sqnc_split_at_mut(&mut self, mid: usize) -> Option<(SliceMut<'_, Self>, SliceMut<'_, Self>)>
     where
-        Self: for<'a> MutSequenceTypes<'a, MutItem = &'a mut Item>,
-        Other: IntoIterator<Item = Item>,
-        Other::IntoIter: ExactSizeIterator,
+        Self: UniqueMutSequence,
     {
-        let other = other.into_iter();
-        if self.len() != other.len() {
+        let len = self.len();
+        if mid > len {
             return None;
         }
-        iter::zip(self.iter_mut(), other).for_each(|(s, o)| *s = o);
-        Some(())
+        let seq: *mut Self = self;
+        // SAFETY: `self` is borrowed mutably for the lifetime of both views,
+        // the ranges `0..mid` and `mid..len` are disjoint and in bounds, and
+        // `Self` implements `UniqueMutSequence`, hence the elements of the two
+        // views don't alias.
+        unsafe { Some((SliceMut::new(seq, 0..mid), SliceMut::new(seq, mid..len))) }
     }
 
     /// Returns a [`Sequence`] that references `self` mutably.
@@ -678,6 +1716,63 @@ impl<S> SequenceRefMut for S where
 {
 }
 
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Trait for tuples of sequences.
+///
+/// This trait is sealed and used by [`crate::multizip()`] and
+/// [`crate::multiproduct()`]. It is implemented for tuples of up to twelve
+/// types that implement [`Sequence`].
+pub trait SequenceTuple: sealed::Sealed {
+    /// Returns `true` if all sequences have the same length.
+    fn has_equal_lengths(&self) -> bool;
+
+    /// Returns the product of the lengths of all sequences or `None` if the
+    /// product overflows.
+    fn checked_product_of_lengths(&self) -> Option<usize>;
+}
+
+macro_rules! impl_sequence_tuple {
+    ($($seq:ident $idx:tt),+) => {
+        impl<$($seq),+> sealed::Sealed for ($($seq,)+) where $($seq: Sequence,)+ {}
+
+        impl<$($seq),+> SequenceTuple for ($($seq,)+)
+        where
+            $($seq: Sequence,)+
+        {
+            #[inline]
+            fn has_equal_lengths(&self) -> bool {
+                let len = self.0.len();
+                true $(&& self.$idx.len() == len)+
+            }
+
+            #[inline]
+            fn checked_product_of_lengths(&self) -> Option<usize> {
+                if false $(|| self.$idx.is_empty())+ {
+                    Some(0)
+                } else {
+                    Some(1usize) $(.and_then(|n| n.checked_mul(self.$idx.len())))+
+                }
+            }
+        }
+    };
+}
+
+impl_sequence_tuple! {S0 0}
+impl_sequence_tuple! {S0 0, S1 1}
+impl_sequence_tuple! {S0 0, S1 1, S2 2}
+impl_sequence_tuple! {S0 0, S1 1, S2 2, S3 3}
+impl_sequence_tuple! {S0 0, S1 1, S2 2, S3 3, S4 4}
+impl_sequence_tuple! {S0 0, S1 1, S2 2, S3 3, S4 4, S5 5}
+impl_sequence_tuple! {S0 0, S1 1, S2 2, S3 3, S4 4, S5 5, S6 6}
+impl_sequence_tuple! {S0 0, S1 1, S2 2, S3 3, S4 4, S5 5, S6 6, S7 7}
+impl_sequence_tuple! {S0 0, S1 1, S2 2, S3 3, S4 4, S5 5, S6 6, S7 7, S8 8}
+impl_sequence_tuple! {S0 0, S1 1, S2 2, S3 3, S4 4, S5 5, S6 6, S7 7, S8 8, S9 9}
+impl_sequence_tuple! {S0 0, S1 1, S2 2, S3 3, S4 4, S5 5, S6 6, S7 7, S8 8, S9 9, S10 10}
+impl_sequence_tuple! {S0 0, S1 1, S2 2, S3 3, S4 4, S5 5, S6 6, S7 7, S8 8, S9 9, S10 10, S11 11}
+
 /// Trait for obtaining a reference to a type that implements [`Sequence`].
 ///
 /// This trait is used by [`crate::wrap()`].
@@ -764,6 +1859,35 @@ where
 /// guarantees.
 pub unsafe trait UniqueSequence: Sequence {}
 
+/// A mutable sequence that contains unique mutable elements.
+///
+/// [`MutSequence::get_mut()`] returns unique elements for unique indices and
+/// [`MutSequence::iter_mut()`] returns an iterator that produces unique
+/// elements. Contrary to [`UniqueSequence`], nothing is implied about the
+/// immutable elements returned by [`Sequence::get()`].
+///
+/// If the element type is a reference, the uniqueness applies not to the
+/// referent, but to the reference, implying that the elements don't alias.
+///
+/// # Safety
+///
+/// This trait must only be implemented when the above contract is upheld and
+/// [`MutSequence::get_mut()`] does not modify the sequence other than via the
+/// returned element. Hence calling [`MutSequence::get_mut()`] with an index
+/// does not invalidate elements previously returned for other indices. The
+/// mutable adaptors, e.g. [`MutSequence::sqnc_split_at_mut()`], rely on
+/// this to hand out elements for disjoint indices simultaneously.
+///
+/// # Notes
+///
+/// A [`crate::Concat`] of two mutable sequences implements
+/// [`UniqueMutSequence`] if both parts do: the parts are borrowed mutably
+/// through different fields, hence their mutable elements cannot alias. The
+/// immutable elements, however, may alias, e.g. when concatenating a slice
+/// with itself, hence [`crate::Concat`] does not implement
+/// [`UniqueSequence`].
+pub unsafe trait UniqueMutSequence: MutSequence {}
+
 /// An iterator that returns unique elements.
 ///
 /// Every call to [`Iterator::next()`] or [`DoubleEndedIterator::next_back()`],
@@ -783,6 +1907,16 @@ pub unsafe trait UniqueIterator: Iterator {}
 unsafe impl<Iter> UniqueIterator for iter::Rev<Iter> where Iter: UniqueIterator + DoubleEndedIterator
 {}
 
+// SAFETY: Skipping elements of a unique iterator does not change uniqueness.
+unsafe impl<Iter> UniqueIterator for iter::StepBy<Iter> where Iter: UniqueIterator {}
+
+// SAFETY: Fusing an iterator does not change the returned elements.
+unsafe impl<Iter> UniqueIterator for iter::Fuse<Iter> where Iter: UniqueIterator {}
+
+// SAFETY: Pairing the elements of a unique iterator with indices does not
+// change uniqueness.
+unsafe impl<Iter> UniqueIterator for iter::Enumerate<Iter> where Iter: UniqueIterator {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -821,6 +1955,12 @@ mod tests {
         }
     }
 
+    // SAFETY: `Minimal::get_mut()` defers to `slice::get_mut()`.
+    unsafe impl<'a> UniqueSequence for Minimal<'a> {}
+
+    // SAFETY: See above.
+    unsafe impl<'a> UniqueMutSequence for Minimal<'a> {}
+
     #[test]
     fn is_empty() {
         let mut x = [2, 3, 4];
@@ -872,6 +2012,73 @@ mod tests {
         assert_eq!(z.max(), None);
     }
 
+    #[test]
+    fn binary_search() {
+        let mut x = [1, 3, 3, 5];
+        let y = Minimal(&mut x);
+        assert_eq!(y.binary_search(&0), Err(0));
+        assert_eq!(y.binary_search(&1), Ok(0));
+        assert!(matches!(y.binary_search(&3), Ok(1) | Ok(2)));
+        assert_eq!(y.binary_search(&4), Err(3));
+        assert_eq!(y.binary_search(&5), Ok(3));
+        assert_eq!(y.binary_search(&6), Err(4));
+        let z = Minimal(&mut x[0..0]);
+        assert_eq!(z.binary_search(&1), Err(0));
+    }
+
+    #[test]
+    fn binary_search_by() {
+        let mut x = [5, 3, 3, 1];
+        let y = Minimal(&mut x);
+        assert_eq!(y.binary_search_by(|v| 5.cmp(v)), Ok(0));
+        assert_eq!(y.binary_search_by(|v| 2.cmp(v)), Err(3));
+        assert_eq!(y.binary_search_by(|v| 0.cmp(v)), Err(4));
+    }
+
+    #[test]
+    fn binary_search_by_key() {
+        let mut x = [1, 3, 3, 5];
+        let y = Minimal(&mut x);
+        assert_eq!(y.binary_search_by_key(&10, |v| v * 2), Ok(3));
+        assert_eq!(y.binary_search_by_key(&5, |v| v * 2), Err(1));
+    }
+
+    #[test]
+    fn partition_point() {
+        let mut x = [1, 3, 3, 5];
+        let y = Minimal(&mut x);
+        assert_eq!(y.partition_point(|&v| v < 1), 0);
+        assert_eq!(y.partition_point(|&v| v < 3), 1);
+        assert_eq!(y.partition_point(|&v| v <= 3), 3);
+        assert_eq!(y.partition_point(|&v| v < 9), 4);
+    }
+
+    #[test]
+    fn seq_eq() {
+        let mut x = [2, 3, 4];
+        let y = Minimal(&mut x);
+        assert!(y.seq_eq(&[2, 3, 4]));
+        assert!(!y.seq_eq(&[2, 3]));
+        assert!(!y.seq_eq(&[2, 3, 5]));
+    }
+
+    #[test]
+    fn seq_cmp() {
+        let mut x = [2, 3, 4];
+        let y = Minimal(&mut x);
+        assert_eq!(y.seq_cmp(&[2, 3, 4]), Ordering::Equal);
+        assert_eq!(y.seq_cmp(&[2, 3]), Ordering::Greater);
+        assert_eq!(y.seq_cmp(&[2, 4]), Ordering::Less);
+    }
+
+    #[test]
+    fn seq_partial_cmp() {
+        let mut x = [2, 3];
+        let y = Minimal(&mut x);
+        assert_eq!(y.seq_partial_cmp(&[2, 3]), Some(Ordering::Equal));
+        assert_eq!(y.seq_partial_cmp(&[2, 2, 1]), Some(Ordering::Greater));
+    }
+
     #[test]
     fn copied() {
         let mut x = [2, 3, 4];
@@ -914,6 +2121,15 @@ mod tests {
         assert!(z.iter().eq([4, 3, 2].iter()));
     }
 
+    #[test]
+    fn sqnc_step_by() {
+        let mut x = [2, 3, 4];
+        let y = Minimal(&mut x);
+        let z = y.sqnc_step_by(2).unwrap();
+        assert!(z.iter().eq([2, 4].iter()));
+        assert!(Minimal(&mut x).sqnc_step_by(0).is_none());
+    }
+
     #[test]
     fn concat() {
         let mut x = [2, 3, 4];
@@ -924,6 +2140,25 @@ mod tests {
         assert_eq!(z.get(3), Some(&5));
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn flatten() {
+        let mut x = [2, 3, 4];
+        let y = Minimal(&mut x);
+        let z = y.sqnc_chunks(2).unwrap();
+        let w = z.flatten().unwrap();
+        assert!(w.iter().eq([2, 3, 4].iter()));
+    }
+
+    #[test]
+    fn interleave() {
+        let mut x = [2, 3, 4];
+        let mut y = [5, 6, 7];
+        let z = Sequence::interleave(Minimal(&mut x), Minimal(&mut y)).unwrap();
+        assert!(z.iter().eq([2, 5, 3, 6, 4, 7].iter()));
+        assert!(Sequence::interleave(Minimal(&mut x), Minimal(&mut y[0..2])).is_none());
+    }
+
     #[test]
     fn select() {
         let mut x = [2, 3, 4];
@@ -935,6 +2170,74 @@ mod tests {
         assert!(Sequence::select(Minimal(&mut x), 3..5).is_none());
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn select_unique() {
+        let mut x = [2, 3, 4];
+        let mut y = Minimal(&mut x).select_unique([2, 0]).unwrap();
+        assert_eq!(y.get(0), Some(&4));
+        *y.get_mut(1).unwrap() = 5;
+        assert_eq!(x, [5, 3, 4]);
+        assert!(Minimal(&mut x).select_unique([2, 2]).is_none());
+        assert!(Minimal(&mut x).select_unique([3]).is_none());
+        assert!(Minimal(&mut x).select_unique([usize::MAX]).is_none());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn compress() {
+        let mut x = [2, 3, 4];
+        let mut y = Minimal(&mut x)
+            .compress([true, false, true].copied())
+            .unwrap();
+        assert_eq!(y.len(), 2);
+        assert_eq!(y.get(1), Some(&4));
+        *y.get_mut(0).unwrap() = 5;
+        assert_eq!(x, [5, 3, 4]);
+        assert!(Minimal(&mut x).compress([true].copied()).is_none());
+    }
+
+    #[test]
+    fn sqnc_slice() {
+        let mut x = [2, 3, 4];
+        let y = Minimal(&mut x);
+        let z = y.sqnc_slice(1..).unwrap();
+        assert_eq!(z.len(), 2);
+        assert_eq!(z.get(0), Some(&3));
+        assert_eq!(z.get(1), Some(&4));
+        assert!(Sequence::sqnc_slice(Minimal(&mut x), 2..4).is_none());
+    }
+
+    #[test]
+    fn sqnc_split_at() {
+        let mut x = [2, 3, 4];
+        let y = Minimal(&mut x);
+        let (a, b) = y.as_sqnc().sqnc_split_at(1).unwrap();
+        assert!(a.iter().eq(&[2]));
+        assert!(b.iter().eq(&[3, 4]));
+        let (a, b) = y.as_sqnc().sqnc_split_at(3).unwrap();
+        assert!(a.iter().eq(&[2, 3, 4]));
+        assert!(b.is_empty());
+        assert!(y.as_sqnc().sqnc_split_at(4).is_none());
+    }
+
+    #[test]
+    fn sqnc_product() {
+        let mut x = [2, 3, 4];
+        let y = Minimal(&mut x);
+        let z = y.sqnc_product(0..2).unwrap();
+        assert_eq!(z.len(), 6);
+        assert_eq!(z.get(3), Some((&3, 1)));
+    }
+
+    #[test]
+    fn sqnc_enumerate() {
+        let mut x = [2, 3, 4];
+        let y = Minimal(&mut x);
+        let z = y.sqnc_enumerate();
+        assert_eq!(z.get(1), Some((1, &3)));
+    }
+
     #[test]
     fn zip() {
         let mut x = [2, 3, 4];
@@ -945,6 +2248,38 @@ mod tests {
         assert!(Sequence::zip(Minimal(&mut x), Minimal(&mut y[0..2])).is_none());
     }
 
+    #[test]
+    fn sqnc_windows() {
+        let mut x = [2, 3, 4];
+        let y = Minimal(&mut x);
+        let z = y.sqnc_windows(2).unwrap();
+        assert_eq!(z.len(), 2);
+        assert!(z.get(0).unwrap().iter().eq(&[2, 3]));
+        assert!(z.get(1).unwrap().iter().eq(&[3, 4]));
+        assert!(Sequence::sqnc_windows(Minimal(&mut x), 0).is_none());
+    }
+
+    #[test]
+    fn sqnc_chunks() {
+        let mut x = [2, 3, 4];
+        let y = Minimal(&mut x);
+        let z = y.sqnc_chunks(2).unwrap();
+        assert_eq!(z.len(), 2);
+        assert!(z.get(0).unwrap().iter().eq(&[2, 3]));
+        assert!(z.get(1).unwrap().iter().eq(&[4]));
+        assert!(Sequence::sqnc_chunks(Minimal(&mut x), 0).is_none());
+    }
+
+    #[test]
+    fn sqnc_chunks_exact() {
+        let mut x = [2, 3, 4];
+        let y = Minimal(&mut x);
+        let z = y.sqnc_chunks_exact(2).unwrap();
+        assert_eq!(z.len(), 1);
+        assert!(z.get(0).unwrap().iter().eq(&[2, 3]));
+        assert!(Sequence::sqnc_chunks_exact(Minimal(&mut x), 0).is_none());
+    }
+
     #[test]
     fn as_sqnc() {
         let mut x = [0, 1, 2, 3];
@@ -998,6 +2333,154 @@ mod tests {
         assert!(y.assign(4..7).is_none());
     }
 
+    #[test]
+    fn sqnc_chunks_mut() {
+        let mut x = [2, 3, 4];
+        let mut y = Minimal(&mut x);
+        let mut iter = y.sqnc_chunks_mut(2).unwrap();
+        let mut a = iter.next().unwrap();
+        let mut b = iter.next().unwrap();
+        assert!(iter.next().is_none());
+        *b.first_mut().unwrap() = 5;
+        a.iter_mut().for_each(|v| *v += 4);
+        assert!(y.sqnc_chunks_mut(0).is_none());
+        assert_eq!(x, [6, 7, 5]);
+    }
+
+    #[test]
+    fn sqnc_split_at_mut() {
+        let mut x = [2, 3, 4];
+        let mut y = Minimal(&mut x);
+        let (mut a, mut b) = y.sqnc_split_at_mut(1).unwrap();
+        *a.first_mut().unwrap() = 5;
+        b.iter_mut().for_each(|v| *v += 4);
+        assert!(y.sqnc_split_at_mut(4).is_none());
+        assert_eq!(x, [5, 7, 8]);
+    }
+
+    #[test]
+    fn sqnc_split_at_mut_rev() {
+        let mut x = [2, 3, 4];
+        let mut y = Minimal(&mut x).rev();
+        let (mut a, mut b) = y.sqnc_split_at_mut(2).unwrap();
+        let b0 = b.first_mut().unwrap();
+        a.iter_mut().for_each(|v| *v += *b0);
+        *b0 = 0;
+        assert_eq!(x, [0, 5, 6]);
+    }
+
+    #[test]
+    fn sqnc_split_at_mut_select() {
+        let mut x = [2, 3, 4, 5];
+        let mut y = Minimal(&mut x).select(Sequence::rev(1..4)).unwrap();
+        let (mut a, mut b) = y.sqnc_split_at_mut(1).unwrap();
+        *a.first_mut().unwrap() += 10;
+        b.iter_mut().for_each(|v| *v += 20);
+        assert_eq!(x, [2, 23, 24, 15]);
+    }
+
+    #[test]
+    fn sqnc_swap() {
+        let mut x = [2, 3, 4];
+        let mut y = Minimal(&mut x);
+        assert_eq!(y.sqnc_swap(0, 2), Some(()));
+        assert_eq!(y.sqnc_swap(1, 1), Some(()));
+        assert_eq!(y.sqnc_swap(1, 3), None);
+        assert_eq!(x, [4, 3, 2]);
+    }
+
+    #[test]
+    fn sqnc_reverse() {
+        let mut x = [2, 3, 4, 5];
+        let mut y = Minimal(&mut x);
+        y.sqnc_reverse();
+        assert_eq!(x, [5, 4, 3, 2]);
+        let mut z = Minimal(&mut x[0..3]);
+        z.sqnc_reverse();
+        assert_eq!(x, [3, 4, 5, 2]);
+    }
+
+    #[test]
+    fn sqnc_rotate_left() {
+        let mut x = [2, 3, 4, 5];
+        let mut y = Minimal(&mut x);
+        assert_eq!(y.sqnc_rotate_left(1), Some(()));
+        assert_eq!(y.sqnc_rotate_left(5), None);
+        assert_eq!(x, [3, 4, 5, 2]);
+    }
+
+    #[test]
+    fn sqnc_rotate_right() {
+        let mut x = [2, 3, 4, 5];
+        let mut y = Minimal(&mut x);
+        assert_eq!(y.sqnc_rotate_right(1), Some(()));
+        assert_eq!(y.sqnc_rotate_right(5), None);
+        assert_eq!(x, [5, 2, 3, 4]);
+    }
+
+    #[test]
+    fn sqnc_fill() {
+        let mut x = [2, 3, 4, 5];
+        let mut y = Minimal(&mut x);
+        y.sqnc_fill(6);
+        assert_eq!(x, [6, 6, 6, 6]);
+    }
+
+    #[test]
+    fn sqnc_sort_unstable() {
+        let mut x = [3, 1, 4, 1, 5, 9, 2, 6];
+        let mut y = Minimal(&mut x);
+        y.sqnc_sort_unstable();
+        assert_eq!(x, [1, 1, 2, 3, 4, 5, 6, 9]);
+        let mut z = Minimal(&mut x[0..0]);
+        z.sqnc_sort_unstable();
+    }
+
+    #[test]
+    fn sqnc_sort_unstable_by() {
+        let mut x = [3, 1, 4, 1, 5, 9, 2, 6];
+        let mut y = Minimal(&mut x);
+        y.sqnc_sort_unstable_by(|a, b| b.cmp(a));
+        assert_eq!(x, [9, 6, 5, 4, 3, 2, 1, 1]);
+    }
+
+    #[test]
+    fn sqnc_sort_unstable_by_key() {
+        let mut x = [3, 1, 4, 1, 5, 9, 2, 6];
+        let mut y = Minimal(&mut x);
+        y.sqnc_sort_unstable_by_key(|v| v % 3);
+        assert!(x.iter().map(|v| v % 3).eq([0, 0, 0, 1, 1, 1, 2, 2]));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sqnc_sort() {
+        let mut x = [3, 1, 4, 1, 5, 9, 2, 6];
+        let mut y = Minimal(&mut x);
+        y.sqnc_sort();
+        assert_eq!(x, [1, 1, 2, 3, 4, 5, 6, 9]);
+        let mut z = Minimal(&mut x[0..0]);
+        z.sqnc_sort();
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sqnc_sort_by() {
+        let mut x = [3, 1, 4, 1, 5, 9, 2, 6];
+        let mut y = Minimal(&mut x);
+        y.sqnc_sort_by(|a, b| b.cmp(a));
+        assert_eq!(x, [9, 6, 5, 4, 3, 2, 1, 1]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sqnc_sort_by_key() {
+        let mut x = [3, 1, 4, 1, 5, 9, 2, 6];
+        let mut y = Minimal(&mut x);
+        y.sqnc_sort_by_key(|v| v % 3);
+        assert_eq!(x, [3, 9, 6, 1, 4, 1, 5, 2]);
+    }
+
     #[test]
     fn as_mut_sqnc() {
         let mut x = [0, 1, 2, 3];
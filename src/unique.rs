@@ -0,0 +1,204 @@
+extern crate alloc;
+use crate::derive::{IntoIter, Iter};
+use crate::traits::*;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A sequence of distinct indices.
+///
+/// The indices are checked to be distinct once, upon construction, after which
+/// [`Unique`] implements [`UniqueSequence`]. This allows for mutable
+/// selections with indices that are only known at runtime. See also
+/// [`Sequence::select_unique()`]. Requires feature `alloc`.
+///
+/// The indices are copied into a vector owned by [`Unique`], such that they
+/// cannot change after validation.
+///
+/// # Examples
+///
+/// ```
+/// use sqnc::{MutSequence, Sequence, Unique};
+///
+/// let mut x = [0, 1, 2, 3, 4];
+/// let i = Unique::new(vec![3, 0, 4]).unwrap();
+/// let mut y = x.as_mut_sqnc().select(i).unwrap();
+/// y.iter_mut().for_each(|v| *v *= 10);
+/// assert_eq!(x, [0, 1, 2, 30, 40]);
+///
+/// assert!(Unique::new(vec![3, 0, 3]).is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
+pub struct Unique {
+    indices: Vec<usize>,
+}
+
+impl Unique {
+    /// Returns a sequence of distinct indices or `None` if any index occurs
+    /// more than once.
+    ///
+    /// If the largest index is less than 64 times the number of indices,
+    /// duplicates are detected in linear time with a bitset that is at most
+    /// as large as the indices. Otherwise, e.g. for a few very large
+    /// indices, such a bitset would be disproportionately large and
+    /// duplicates are detected by sorting a copy of the indices instead.
+    pub fn new<I>(indices: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let indices: Vec<usize> = indices.into_iter().collect();
+        let max = indices.iter().copied().max().unwrap_or(0);
+        if max / 64 < indices.len() {
+            let mut bits = vec![0u64; max / 64 + 1];
+            for &index in &indices {
+                let (word, bit) = (index / 64, 1 << (index % 64));
+                if bits[word] & bit != 0 {
+                    return None;
+                }
+                bits[word] |= bit;
+            }
+        } else {
+            let mut sorted = indices.clone();
+            sorted.sort_unstable();
+            if sorted.windows(2).any(|pair| pair[0] == pair[1]) {
+                return None;
+            }
+        }
+        Some(Self { indices })
+    }
+
+    /// Returns the indices as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Returns the indices as a vector.
+    #[inline]
+    pub fn unwrap(self) -> Vec<usize> {
+        self.indices
+    }
+}
+
+impl<'this> SequenceTypes<'this> for Unique {
+    type Item = usize;
+    type Iter = Iter<'this, Self>;
+}
+
+impl Sequence for Unique {
+    #[inline]
+    fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<usize> {
+        self.indices.get(index).copied()
+    }
+
+    #[inline]
+    fn iter(&self) -> Iter<'_, Self> {
+        self.into()
+    }
+}
+
+// SAFETY: The indices are checked to be distinct upon construction and are
+// owned by `Unique`, which doesn't provide mutable access.
+unsafe impl UniqueSequence for Unique {}
+
+impl IntoIterator for Unique {
+    type Item = usize;
+    type IntoIter = IntoIter<Self>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Unique {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let indices = Vec::<usize>::deserialize(deserializer)?;
+        Self::new(indices).ok_or_else(|| serde::de::Error::custom("the indices are not distinct"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::Unique;
+    use crate::traits::*;
+    use alloc::vec;
+
+    #[test]
+    fn new() {
+        assert!(Unique::new([2, 0, 5]).is_some());
+        assert!(Unique::new([]).is_some());
+        assert!(Unique::new([2, 0, 2]).is_none());
+        assert!(Unique::new([64, 0, 64]).is_none());
+        assert!(Unique::new([0, 64, 128, 63]).is_some());
+        assert!(Unique::new([usize::MAX, 0]).is_some());
+        assert!(Unique::new([usize::MAX, 0, usize::MAX]).is_none());
+        assert!(Unique::new([1000, 3, 999]).is_some());
+        assert!(Unique::new([1000, 3, 1000]).is_none());
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(Unique::new([2, 0, 5]).unwrap().len(), 3);
+        assert_eq!(Unique::new([]).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn get() {
+        let x = Unique::new([2, 0, 5]).unwrap();
+        assert_eq!(x.get(0), Some(2));
+        assert_eq!(x.get(2), Some(5));
+        assert_eq!(x.get(3), None);
+    }
+
+    #[test]
+    fn iter() {
+        let x = Unique::new([2, 0, 5]).unwrap();
+        assert!(x.iter().eq([2, 0, 5]));
+        assert!(x.iter().rev().eq([5, 0, 2]));
+        assert!(x.into_iter().eq([2, 0, 5]));
+    }
+
+    #[test]
+    fn as_slice_unwrap() {
+        let x = Unique::new(vec![2, 0, 5]).unwrap();
+        assert_eq!(x.as_slice(), &[2, 0, 5]);
+        assert_eq!(x.unwrap(), vec![2, 0, 5]);
+    }
+
+    #[test]
+    fn select_mut() {
+        let mut x = [0, 1, 2, 3, 4];
+        let i = Unique::new([4, 1, 2]).unwrap();
+        let mut y = x.as_mut_sqnc().select(i).unwrap();
+        *y.get_mut(0).unwrap() = 5;
+        y.iter_mut().for_each(|v| *v += 1);
+        assert_eq!(x, [0, 2, 3, 3, 6]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+        let tokens = |last| {
+            [
+                Token::Seq { len: Some(2) },
+                Token::U64(2),
+                Token::U64(last),
+                Token::SeqEnd,
+            ]
+        };
+        assert_tokens(&Unique::new([2, 0]).unwrap(), &tokens(0));
+        assert_de_tokens_error::<Unique>(&tokens(2), "the indices are not distinct");
+    }
+}
@@ -0,0 +1,169 @@
+use crate::derive::Iter;
+use crate::traits::*;
+use crate::{Slice, Wrapper};
+
+/// A sequence of overlapping windows of a sequence.
+///
+/// This struct is created by [`Sequence::sqnc_windows()`]. See its
+/// documentation for more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Windows<Seq> {
+    seq: Seq,
+    size: usize,
+}
+
+impl<Seq> Windows<Seq>
+where
+    Seq: Sequence,
+{
+    #[inline]
+    pub(crate) fn new(seq: Seq, size: usize) -> Option<Self> {
+        (size > 0).then_some(Self { seq, size })
+    }
+}
+
+impl<'this, Seq> SequenceTypes<'this> for Windows<Seq>
+where
+    Seq: Sequence,
+{
+    type Item = Slice<Wrapper<&'this Seq, ((),)>>;
+    type Iter = Iter<'this, Self>;
+}
+
+impl<Seq> Sequence for Windows<Seq>
+where
+    Seq: Sequence,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.seq.len().saturating_sub(self.size - 1)
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<<Self as SequenceTypes<'_>>::Item> {
+        if index < self.len() {
+            Slice::new(self.seq.as_sqnc(), index..index + self.size)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn iter(&self) -> Iter<'_, Self> {
+        self.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Windows;
+    use crate::traits::*;
+
+    #[test]
+    fn new() {
+        assert!(Windows::new(0..3, 2).is_some());
+        assert!(Windows::new(0..3, 0).is_none());
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(Windows::new(0..4, 1).unwrap().len(), 4);
+        assert_eq!(Windows::new(0..4, 2).unwrap().len(), 3);
+        assert_eq!(Windows::new(0..4, 4).unwrap().len(), 1);
+        assert_eq!(Windows::new(0..4, 5).unwrap().len(), 0);
+        assert_eq!(Windows::new(0..0, 2).unwrap().len(), 0);
+        assert_eq!(
+            Windows::new(0..usize::MAX, 2).unwrap().len(),
+            usize::MAX - 1
+        );
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(!Windows::new(0..4, 2).unwrap().is_empty());
+        assert!(Windows::new(0..4, 5).unwrap().is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let x = Windows::new(2..6, 2).unwrap();
+        assert!(x.get(0).unwrap().iter().eq(2..4));
+        assert!(x.get(1).unwrap().iter().eq(3..5));
+        assert!(x.get(2).unwrap().iter().eq(4..6));
+        assert!(x.get(3).is_none());
+    }
+
+    #[test]
+    fn rget() {
+        let x = Windows::new(2..6, 2).unwrap();
+        assert!(x.rget(0).unwrap().iter().eq(4..6));
+        assert!(x.rget(1).unwrap().iter().eq(3..5));
+        assert!(x.rget(2).unwrap().iter().eq(2..4));
+        assert!(x.rget(3).is_none());
+    }
+
+    #[test]
+    fn first() {
+        assert!(Windows::new(2..6, 3)
+            .unwrap()
+            .first()
+            .unwrap()
+            .iter()
+            .eq(2..5));
+        assert!(Windows::new(2..6, 5).unwrap().first().is_none());
+    }
+
+    #[test]
+    fn last() {
+        assert!(Windows::new(2..6, 3)
+            .unwrap()
+            .last()
+            .unwrap()
+            .iter()
+            .eq(3..6));
+        assert!(Windows::new(2..6, 5).unwrap().last().is_none());
+    }
+
+    #[test]
+    fn iter() {
+        let x = Windows::new([2, 3, 4, 5], 3).unwrap();
+        let mut iter = x.iter();
+        assert!(iter.next().unwrap().iter().eq(&[2, 3, 4]));
+        assert!(iter.next().unwrap().iter().eq(&[3, 4, 5]));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn rev_iter() {
+        let x = Windows::new([2, 3, 4, 5], 3).unwrap();
+        let mut iter = x.iter().rev();
+        assert!(iter.next().unwrap().iter().eq(&[3, 4, 5]));
+        assert!(iter.next().unwrap().iter().eq(&[2, 3, 4]));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn iter_size_hint() {
+        let x = Windows::new(0..4, 2).unwrap();
+        let mut iter = x.iter();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        iter.next();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+    }
+
+    #[test]
+    fn map() {
+        let x = Windows::new([1, 4, 9, 16], 2).unwrap();
+        let dx = Sequence::map(x, |w| w.get(1).unwrap() - w.get(0).unwrap());
+        assert!(dx.iter().eq([3, 5, 7]));
+    }
+
+    #[test]
+    fn slice_windows() {
+        // `slice::windows()` is not shadowed by `Sequence::sqnc_windows()`.
+        let x = [1, 4, 9, 16];
+        let mut iter = x.windows(2);
+        assert_eq!(iter.next().unwrap()[1], 4);
+        assert_eq!(iter.count(), 2);
+    }
+}
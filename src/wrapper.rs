@@ -166,6 +166,14 @@ where
 {
 }
 
+// SAFETY: See above.
+unsafe impl<S, N> UniqueMutSequence for Wrapper<S, N>
+where
+    S: DerefMutSequence<N>,
+    S::Sequence: UniqueMutSequence,
+{
+}
+
 /// Wraps a type `S` that, after dereferencing `N` times, implements [`Sequence`].
 ///
 /// The returned [`Wrapper`] implements [`Sequence`], and [`MutSequence`] if
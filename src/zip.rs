@@ -108,7 +108,7 @@ where
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter().zip(self.1.into_iter())
+        self.0.into_iter().zip(self.1)
     }
 }
 